@@ -1,7 +1,7 @@
 use dprint_core::{
     configuration::{
-        ConfigKeyMap, ConfigurationDiagnostic, GlobalConfiguration, NewLineKind,
-        get_nullable_value, get_unknown_property_diagnostics, get_value,
+        ConfigKeyMap, ConfigKeyValue, ConfigurationDiagnostic, GlobalConfiguration, NewLineKind,
+        get_nullable_value, get_nullable_vec, get_unknown_property_diagnostics, get_value,
     },
     plugins::{FileMatchingInfo, PluginResolveConfigurationResult},
 };
@@ -357,6 +357,74 @@ pub(crate) fn resolve_config(
                     Default::default()
                 }
             },
+            vue_custom_block_lang: match config.shift_remove("vueCustomBlockLang") {
+                Some(ConfigKeyValue::Object(map)) => map
+                    .into_iter()
+                    .filter_map(|(name, lang)| match lang {
+                        ConfigKeyValue::String(lang) => Some((name, lang)),
+                        _ => {
+                            diagnostics.push(ConfigurationDiagnostic {
+                                property_name: format!("vueCustomBlockLang.{name}"),
+                                message: "expected string".into(),
+                            });
+                            None
+                        }
+                    })
+                    .collect(),
+                Some(ConfigKeyValue::Null) => Default::default(),
+                None => LanguageOptions::default().vue_custom_block_lang,
+                Some(..) => {
+                    diagnostics.push(ConfigurationDiagnostic {
+                        property_name: "vueCustomBlockLang".into(),
+                        message: "invalid value for config `vueCustomBlockLang`".into(),
+                    });
+                    LanguageOptions::default().vue_custom_block_lang
+                }
+            },
+            vue_attribute_order: get_value(
+                &mut config,
+                "vueAttributeOrder",
+                false,
+                &mut diagnostics,
+            ),
+            vue_expr_lang: get_nullable_value::<String>(
+                &mut config,
+                "vue.exprLang",
+                &mut diagnostics,
+            )
+            .as_deref()
+            .and_then(|option_value| match option_value {
+                "js" => Some(ExprLang::Js),
+                "jsx" => Some(ExprLang::Jsx),
+                "ts" => Some(ExprLang::Ts),
+                "tsx" => Some(ExprLang::Tsx),
+                _ => {
+                    diagnostics.push(ConfigurationDiagnostic {
+                        property_name: "vue.exprLang".into(),
+                        message: "invalid value for config `vue.exprLang`".into(),
+                    });
+                    None
+                }
+            }),
+            svelte_expr_lang: get_nullable_value::<String>(
+                &mut config,
+                "svelte.exprLang",
+                &mut diagnostics,
+            )
+            .as_deref()
+            .and_then(|option_value| match option_value {
+                "js" => Some(ExprLang::Js),
+                "jsx" => Some(ExprLang::Jsx),
+                "ts" => Some(ExprLang::Ts),
+                "tsx" => Some(ExprLang::Tsx),
+                _ => {
+                    diagnostics.push(ConfigurationDiagnostic {
+                        property_name: "svelte.exprLang".into(),
+                        message: "invalid value for config `svelte.exprLang`".into(),
+                    });
+                    None
+                }
+            }),
             strict_svelte_attr: get_value(&mut config, "strictSvelteAttr", false, &mut diagnostics),
             svelte_attr_shorthand: get_nullable_value(
                 &mut config,
@@ -379,6 +447,24 @@ pub(crate) fn resolve_config(
                 "astroAttrShorthand",
                 &mut diagnostics,
             ),
+            alpine: get_value(&mut config, "alpine", false, &mut diagnostics),
+            thymeleaf: get_value(&mut config, "thymeleaf", false, &mut diagnostics),
+            json_attrs: get_nullable_vec(
+                &mut config,
+                "jsonAttrs",
+                |value, index, diagnostics| match value {
+                    ConfigKeyValue::String(value) => Some(value),
+                    _ => {
+                        diagnostics.push(ConfigurationDiagnostic {
+                            property_name: format!("jsonAttrs[{index}]"),
+                            message: "expected string".into(),
+                        });
+                        None
+                    }
+                },
+                &mut diagnostics,
+            )
+            .unwrap_or_default(),
             script_formatter: get_nullable_value::<String>(
                 &mut config,
                 "scriptFormatter",
@@ -439,6 +525,23 @@ pub(crate) fn resolve_config(
                 "xsd",
                 "xslt",
                 "xsl",
+                "liquid",
+                "django",
+                "djhtml",
+                "gohtml",
+                "gotmpl",
+                "tmpl",
+                "erb",
+                "ejs",
+                "blade.php",
+                "cshtml",
+                "razor",
+                "heex",
+                "gjs",
+                "gts",
+                "php",
+                "phtml",
+                "mdx",
             ]
             .into_iter()
             .map(String::from)
@@ -11,8 +11,9 @@ use dprint_core::{
 use markup_fmt::{
     FormatError, Hints,
     config::{FormatOptions, Quotes, ScriptFormatter},
-    detect_language, format_text,
+    detect_language, format_glimmer_script, format_text,
 };
+use std::borrow::Cow;
 
 mod config;
 
@@ -56,34 +57,21 @@ impl SyncPluginHandler<FormatOptions> for MarkupFmtPluginHandler {
     ) -> FormatResult {
         // falling back to HTML allows to format files with unknown extensions, such as .svg
         let language = detect_language(request.file_path).unwrap_or(markup_fmt::Language::Html);
-
-        let format_result = format_text(
-            std::str::from_utf8(&request.file_bytes)?,
-            language,
-            request.config,
-            |code, hints| {
-                let mut file_name = request
-                    .file_path
-                    .file_name()
-                    .expect("missing file name")
-                    .to_owned();
-                file_name.push("#.");
-                file_name.push(hints.ext);
-                let additional_config = build_additional_config(hints, request.config);
-                format_with_host(SyncHostFormatRequest {
-                    file_path: &request.file_path.with_file_name(file_name),
-                    file_bytes: code.as_bytes(),
-                    range: None,
-                    override_config: &additional_config,
-                })
-                .and_then(|result| match result {
-                    Some(code) => String::from_utf8(code)
-                        .map(|s| s.into())
-                        .map_err(anyhow::Error::from),
-                    None => Ok(code.into()),
-                })
-            },
+        let is_glimmer_script = matches!(
+            request.file_path.extension().and_then(|ext| ext.to_str()),
+            Some("gjs" | "gts")
         );
+
+        let code = std::str::from_utf8(&request.file_bytes)?;
+        let format_result = if is_glimmer_script {
+            format_glimmer_script(code, request.config, |code, hints| {
+                format_embedded(code, hints, &request, &mut format_with_host)
+            })
+        } else {
+            format_text(code, language, request.config, |code, hints| {
+                format_embedded(code, hints, &request, &mut format_with_host)
+            })
+        };
         match format_result {
             Ok(code) => Ok(Some(code.into_bytes())),
             Err(FormatError::Syntax(err)) => Err(err.into()),
@@ -101,6 +89,34 @@ impl SyncPluginHandler<FormatOptions> for MarkupFmtPluginHandler {
     }
 }
 
+fn format_embedded<'a>(
+    code: &'a str,
+    hints: Hints,
+    request: &SyncFormatRequest<FormatOptions>,
+    format_with_host: &mut impl FnMut(SyncHostFormatRequest) -> FormatResult,
+) -> Result<Cow<'a, str>> {
+    let mut file_name = request
+        .file_path
+        .file_name()
+        .expect("missing file name")
+        .to_owned();
+    file_name.push("#.");
+    file_name.push(hints.ext);
+    let additional_config = build_additional_config(hints, request.config);
+    format_with_host(SyncHostFormatRequest {
+        file_path: &request.file_path.with_file_name(file_name),
+        file_bytes: code.as_bytes(),
+        range: None,
+        override_config: &additional_config,
+    })
+    .and_then(|result| match result {
+        Some(code) => String::from_utf8(code)
+            .map(|s| s.into())
+            .map_err(anyhow::Error::from),
+        None => Ok(code.into()),
+    })
+}
+
 #[cfg(target_arch = "wasm32")]
 dprint_core::generate_plugin_code!(
     MarkupFmtPluginHandler,
@@ -0,0 +1,12 @@
+use markup_fmt::{detect_language, parse};
+use std::{env, fs};
+
+fn main() -> anyhow::Result<()> {
+    let file_path = env::args().nth(1).unwrap();
+    let language = detect_language(&file_path).unwrap();
+    let code = fs::read_to_string(file_path)?;
+
+    let ast = parse(&code, language)?;
+    println!("{}", serde_json::to_string_pretty(&ast)?);
+    Ok(())
+}
@@ -1,4 +1,12 @@
+//! Types of AST nodes produced by the parser.
+//!
+//! String slices borrow from the source code, and `usize` values are byte offsets into it.
+
+#[cfg(feature = "ast_serde")]
+use serde::Serialize;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular for loop: `@for ( ... )`.
 ///
 /// See https://angular.dev/api/core/@for.
@@ -12,6 +20,7 @@ pub struct AngularFor<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular conditional block: `@if ( condition )`.
 ///
 /// See https://angular.dev/api/core/@if.
@@ -24,6 +33,7 @@ pub struct AngularIf<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular else-if block: `@else if ( condition )`.
 ///
 /// See https://angular.dev/api/core/@if.
@@ -34,6 +44,7 @@ pub struct AngularElseIf<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub struct AngularGenericBlock<'s> {
     pub keyword: &'s str,
     pub header: Option<&'s str>,
@@ -41,6 +52,7 @@ pub struct AngularGenericBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular interpolation: `{{ expression }}`.
 ///
 /// See https://angular.dev/guide/templates/binding#render-dynamic-text-with-text-interpolation.
@@ -50,6 +62,7 @@ pub struct AngularInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular let variable declaration: `@let name = expression`.
 ///
 /// See https://angular.dev/api/core/@let.
@@ -59,6 +72,7 @@ pub struct AngularLet<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Angular switch statement: `@switch (expression)`.
 ///
 /// See https://angular.dev/api/core/@switch.
@@ -68,6 +82,7 @@ pub struct AngularSwitch<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// `@case` or `@default` arm of an `AngularSwitch`.
 ///
 /// See https://angular.dev/api/core/@switch.
@@ -79,15 +94,18 @@ pub struct AngularSwitchArm<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Astro attribute: `{expression}` or `name={expression}`.
 ///
 /// See https://docs.astro.build/en/reference/astro-syntax/#dynamic-attributes.
 pub struct AstroAttribute<'s> {
     pub name: Option<&'s str>,
     pub expr: (&'s str, usize),
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Astro expression block: `{...}`.
 ///
 /// See https://docs.astro.build/en/reference/astro-syntax/#dynamic-html.
@@ -98,6 +116,7 @@ pub struct AstroExpr<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// See https://docs.astro.build/en/core-concepts/astro-syntax/#dynamic-html.
 pub enum AstroExprChild<'s> {
     Script(&'s str),
@@ -105,8 +124,12 @@ pub enum AstroExprChild<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub enum Attribute<'s> {
     Astro(AstroAttribute<'s>),
+    BladeDirective(BladeDirective<'s>),
+    BladeEcho(BladeEcho<'s>),
+    Heex(HeexAttribute<'s>),
     JinjaBlock(JinjaBlock<'s, Attribute<'s>>),
     JinjaComment(JinjaComment<'s>),
     JinjaTag(JinjaTag<'s>),
@@ -119,6 +142,50 @@ pub enum Attribute<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Blade block formed by paired directives: `@if(...)...@else...@endif`.
+///
+/// Directives and children are interleaved,
+/// so there's always one more directive than children.
+///
+/// See https://laravel.com/docs/blade#blade-directives.
+pub struct BladeBlock<'s> {
+    pub directives: Vec<BladeDirective<'s>>,
+    pub children: Vec<Vec<Node<'s>>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Blade comment: `{{-- ... --}}`.
+///
+/// See https://laravel.com/docs/blade#comments.
+pub struct BladeComment<'s> {
+    pub raw: &'s str,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Blade directive: `@name` or `@name(arguments)`.
+///
+/// See https://laravel.com/docs/blade#blade-directives.
+pub struct BladeDirective<'s> {
+    pub name: &'s str,
+    pub args: Option<(&'s str, usize)>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Blade echo: `{{ expression }}`, or `{!! expression !!}` without escaping.
+///
+/// See https://laravel.com/docs/blade#displaying-data.
+pub struct BladeEcho<'s> {
+    pub expr: &'s str,
+    pub start: usize,
+    pub escaped: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// `<![CDATA[ ... ]]>`
 ///
 /// See https://www.w3.org/TR/xml/#sec-cdata-sect
@@ -127,6 +194,7 @@ pub struct Cdata<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Comment in HTML: `<!-- ... -->`.
 ///
 /// See https://developer.mozilla.org/en-US/docs/Web/HTML/Comments
@@ -135,6 +203,7 @@ pub struct Comment<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// HTML doctype declaration: `<!DOCTYPE ...>`.
 ///
 /// See https://developer.mozilla.org/en-US/docs/Glossary/Doctype
@@ -144,6 +213,7 @@ pub struct Doctype<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// HTML element with its attributes and children.
 ///
 /// See https://developer.mozilla.org/en-US/docs/Web/HTML/Element
@@ -157,6 +227,7 @@ pub struct Element<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Front matter content in a file, typically enclosed in `---`.
 ///
 /// See https://docs.astro.build/en/guides/markdown-content/.
@@ -166,6 +237,18 @@ pub struct FrontMatter<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// HEEx attribute with Elixir expression: `{@attrs}` or `name={expression}`.
+///
+/// See https://hexdocs.pm/phoenix_live_view/assigns-eex.html#heex-extensions.
+pub struct HeexAttribute<'s> {
+    pub name: Option<&'s str>,
+    pub expr: (&'s str, usize),
+    pub start: usize,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Jinja block containing nested Jinja tags or HTML elements.
 ///
 /// See https://jinja.palletsprojects.com/en/stable/templates/#list-of-control-structures.
@@ -174,6 +257,7 @@ pub struct JinjaBlock<'s, T> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Jinja comment: `{# ... #}`.
 ///
 /// See https://jinja.palletsprojects.com/en/stable/templates/#comments.
@@ -182,6 +266,7 @@ pub struct JinjaComment<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Jinja interpolation: `{{ ... }}`.
 ///
 /// See https://jinja.palletsprojects.com/en/stable/templates/#expressions.
@@ -193,6 +278,7 @@ pub struct JinjaInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Jinja tag: `{% ... %}`.
 ///
 /// See https://jinja.palletsprojects.com/en/stable/templates/#list-of-control-structures.
@@ -202,18 +288,40 @@ pub struct JinjaTag<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub enum JinjaTagOrChildren<'s, T> {
     Tag(JinjaTag<'s>),
     Children(Vec<T>),
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub struct JsComment<'s> {
     pub block: bool,
     pub raw: &'s str,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// ESM `import` and `export` statements in MDX, which end at a blank line.
+///
+/// See https://mdxjs.com/docs/what-is-mdx/#esm.
+pub struct MdxEsm<'s> {
+    pub raw: &'s str,
+    pub start: usize,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Markdown content in MDX between JSX blocks, ESM statements or expressions,
+/// or inside JSX elements when it starts on its own line.
+pub struct MdxMarkdown<'s> {
+    pub raw: &'s str,
+    pub start: usize,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Mustache block: `{{#variable}}{{/variable}}`.
 ///
 /// See https://mustache.github.io/mustache.5.html
@@ -223,6 +331,7 @@ pub struct MustacheBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub struct MustacheBlockControl<'s> {
     pub name: &'s str,
     pub prefix: &'s str,
@@ -232,6 +341,7 @@ pub struct MustacheBlockControl<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Mustache interpolation: `{{expression}}`.
 ///
 /// See https://mustache.github.io/mustache.5.html
@@ -240,6 +350,7 @@ pub struct MustacheInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Standard HTML attribute.
 ///
 /// See https://developer.mozilla.org/en-US/docs/Glossary/Attribute
@@ -247,15 +358,20 @@ pub struct NativeAttribute<'s> {
     pub name: &'s str,
     pub value: Option<(&'s str, usize)>,
     pub quote: Option<char>,
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub struct Node<'s> {
     pub kind: NodeKind<'s>,
     pub raw: &'s str,
+    /// Byte offset of `raw` in source code.
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub enum NodeKind<'s> {
     AngularFor(AngularFor<'s>),
     AngularGenericBlocks(Vec<AngularGenericBlock<'s>>),
@@ -264,6 +380,10 @@ pub enum NodeKind<'s> {
     AngularLet(AngularLet<'s>),
     AngularSwitch(AngularSwitch<'s>),
     AstroExpr(AstroExpr<'s>),
+    BladeBlock(BladeBlock<'s>),
+    BladeComment(BladeComment<'s>),
+    BladeDirective(BladeDirective<'s>),
+    BladeEcho(BladeEcho<'s>),
     Cdata(Cdata<'s>),
     Comment(Comment<'s>),
     Doctype(Doctype<'s>),
@@ -273,8 +393,15 @@ pub enum NodeKind<'s> {
     JinjaComment(JinjaComment<'s>),
     JinjaInterpolation(JinjaInterpolation<'s>),
     JinjaTag(JinjaTag<'s>),
+    MdxEsm(MdxEsm<'s>),
+    MdxMarkdown(MdxMarkdown<'s>),
     MustacheBlock(MustacheBlock<'s>),
     MustacheInterpolation(MustacheInterpolation<'s>),
+    RazorBlock(RazorBlock<'s>),
+    RazorCode(RazorCode<'s>),
+    RazorComment(RazorComment<'s>),
+    RazorDirective(RazorDirective<'s>),
+    RazorExpr(RazorExpr<'s>),
     SvelteAtTag(SvelteAtTag<'s>),
     SvelteAwaitBlock(Box<SvelteAwaitBlock<'s>>),
     SvelteEachBlock(SvelteEachBlock<'s>),
@@ -293,11 +420,74 @@ pub enum NodeKind<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Razor code block with markup children,
+/// such as `@if (...) { ... } else { ... }` or `@section Name { ... }`.
+///
+/// See https://learn.microsoft.com/aspnet/core/mvc/views/razor#control-structures.
+pub struct RazorBlock<'s> {
+    pub branches: Vec<RazorBranch<'s>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub struct RazorBranch<'s> {
+    /// Keyword of this branch, such as `if`, `else if` or `catch`.
+    pub keyword: &'s str,
+    /// Text between keyword and `{`, such as `(condition)` or section name.
+    pub header: Option<(&'s str, usize)>,
+    pub children: Vec<Node<'s>>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Razor C# code block: `@{ ... }`, or `@code { ... }` and `@functions { ... }`.
+///
+/// See https://learn.microsoft.com/aspnet/core/mvc/views/razor#razor-code-blocks.
+pub struct RazorCode<'s> {
+    pub keyword: Option<&'s str>,
+    pub code: &'s str,
+    pub start: usize,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Razor comment: `@* ... *@`.
+///
+/// See https://learn.microsoft.com/aspnet/core/mvc/views/razor#comments.
+pub struct RazorComment<'s> {
+    pub raw: &'s str,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Razor directive which takes the rest of line, such as `@model Type` and `@using Namespace`.
+///
+/// See https://learn.microsoft.com/aspnet/core/mvc/views/razor#directives.
+pub struct RazorDirective<'s> {
+    pub name: &'s str,
+    pub value: &'s str,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+/// Razor expression: implicit `@Model.Name`, or explicit `@(expression)`.
+///
+/// See https://learn.microsoft.com/aspnet/core/mvc/views/razor#razor-expressions.
+pub struct RazorExpr<'s> {
+    pub expr: &'s str,
+    pub start: usize,
+    pub explicit: bool,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub struct Root<'s> {
     pub children: Vec<Node<'s>>,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte `@` tag: (`@render`, `@const`, etc).
 ///
 /// See https://svelte.dev/docs/svelte/@render.
@@ -307,23 +497,28 @@ pub struct SvelteAtTag<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte attribute: `{expression}` or `name={expression}`.
 ///
 /// See https://svelte.dev/docs/svelte/basic-markup#Element-attributes.
 pub struct SvelteAttribute<'s> {
     pub name: Option<&'s str>,
     pub expr: (&'s str, usize),
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte attachment: `{@attach expression}`.
 ///
 /// See https://svelte.dev/docs/svelte/@attach.
 pub struct SvelteAttachment<'s> {
     pub expr: (&'s str, usize),
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte await block `{#await expression}...{:then name}...{:catch name}...{/await}`.
 ///
 /// See https://svelte.dev/docs/svelte/await.
@@ -337,6 +532,7 @@ pub struct SvelteAwaitBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// The `{:catch error}...` part of a `SvelteAwaitBlock`.
 pub struct SvelteCatchBlock<'s> {
     pub binding: Option<(&'s str, usize)>,
@@ -344,6 +540,7 @@ pub struct SvelteCatchBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// The `{:then value}...` part of a `SvelteAwaitBlock`.
 pub struct SvelteThenBlock<'s> {
     pub binding: Option<(&'s str, usize)>,
@@ -351,6 +548,7 @@ pub struct SvelteThenBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte each block: `{#each expression as name}...{/each}`.
 ///
 /// See https://svelte.dev/docs/svelte/each.
@@ -364,6 +562,7 @@ pub struct SvelteEachBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte if block: `{#if expression}...{:else if expression}...{/if}`.
 ///
 /// See https://svelte.dev/docs/svelte/if.
@@ -375,6 +574,7 @@ pub struct SvelteIfBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// The `{:else if condition}...` part of a `SvelteIfBlock`.
 pub struct SvelteElseIfBlock<'s> {
     pub expr: (&'s str, usize),
@@ -382,6 +582,7 @@ pub struct SvelteElseIfBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte interpolation: `{expression}`.
 ///
 /// See https://svelte.dev/docs/svelte/basic-markup#Text-expressions.
@@ -390,6 +591,7 @@ pub struct SvelteInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte key block: `{#key expression}...{/key}`.
 ///
 /// See https://svelte.dev/docs/svelte/key.
@@ -399,6 +601,7 @@ pub struct SvelteKeyBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Svelte snippet block: `{#snippet name()}...{/snippet}`.
 ///
 /// See https://svelte.dev/docs/svelte/snippet.
@@ -408,6 +611,7 @@ pub struct SvelteSnippetBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Plain text node.
 pub struct TextNode<'s> {
     pub raw: &'s str,
@@ -416,6 +620,7 @@ pub struct TextNode<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vento block: `{{ keyword ... }}...{{ /keyword }}`
 ///
 /// See https://vento.js.org/syntax/blocks.
@@ -424,6 +629,7 @@ pub struct VentoBlock<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vento comment: `{{# ... #}}`.
 ///
 /// See https://vento.js.org/syntax/comments/.
@@ -432,6 +638,7 @@ pub struct VentoComment<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vento eval block for JavaScript evaluation: `{{> ... }}`.
 ///
 /// See https://vento.js.org/syntax/javascript/.
@@ -441,6 +648,7 @@ pub struct VentoEval<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vento interpolation `{{ ... }}`.
 ///
 /// See https://vento.js.org/syntax/print/.
@@ -452,6 +660,7 @@ pub struct VentoInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vento tag: `{{ keyword ... }}`.
 ///
 /// See https://vento.js.org/syntax/include/.
@@ -462,12 +671,14 @@ pub struct VentoTag<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 pub enum VentoTagOrChildren<'s> {
     Tag(VentoTag<'s>),
     Children(Vec<Node<'s>>),
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vue directive: `v-if`, `v-for`, etc.
 ///
 /// See https://vuejs.org/guide/essentials/template-syntax.html#directives.
@@ -475,9 +686,11 @@ pub struct VueDirective<'s> {
     pub name: &'s str,
     pub arg_and_modifiers: Option<&'s str>,
     pub value: Option<(&'s str, usize)>,
+    pub start: usize,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// Vue interpolation: `{{ expression }}`.
 ///
 /// See https://vuejs.org/guide/essentials/template-syntax.html#text-interpolation.
@@ -487,6 +700,7 @@ pub struct VueInterpolation<'s> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
 /// XML declaration.
 ///
 /// See https://www.w3.org/TR/xml/#sec-prolog-dtd
@@ -5,7 +5,7 @@
 
 #[cfg(feature = "config_serde")]
 use serde::{Deserialize, Serialize};
-use std::num::NonZeroUsize;
+use std::{collections::HashMap, num::NonZeroUsize};
 
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "config_serde", derive(Serialize, Deserialize))]
@@ -206,6 +206,21 @@ pub struct LanguageOptions {
     pub v_bind_same_name_short_hand: Option<bool>,
     #[cfg_attr(feature = "config_serde", serde(alias = "vueComponentCase"))]
     pub vue_component_case: VueComponentCase,
+    #[cfg_attr(feature = "config_serde", serde(alias = "vueCustomBlockLang"))]
+    pub vue_custom_block_lang: HashMap<String, String>,
+    #[cfg_attr(feature = "config_serde", serde(alias = "vueAttributeOrder"))]
+    pub vue_attribute_order: bool,
+
+    #[cfg_attr(
+        feature = "config_serde",
+        serde(rename = "vue.expr_lang", alias = "vue.exprLang")
+    )]
+    pub vue_expr_lang: Option<ExprLang>,
+    #[cfg_attr(
+        feature = "config_serde",
+        serde(rename = "svelte.expr_lang", alias = "svelte.exprLang")
+    )]
+    pub svelte_expr_lang: Option<ExprLang>,
 
     #[cfg_attr(feature = "config_serde", serde(alias = "strictSvelteAttr"))]
     pub strict_svelte_attr: bool,
@@ -223,6 +238,13 @@ pub struct LanguageOptions {
     )]
     pub angular_next_control_flow_same_line: bool,
 
+    pub alpine: bool,
+
+    pub thymeleaf: bool,
+
+    #[cfg_attr(feature = "config_serde", serde(alias = "jsonAttrs"))]
+    pub json_attrs: Vec<String>,
+
     #[cfg_attr(feature = "config_serde", serde(alias = "scriptFormatter"))]
     pub script_formatter: Option<ScriptFormatter>,
 
@@ -270,11 +292,27 @@ impl Default for LanguageOptions {
             named_v_slot_style: None,
             v_bind_same_name_short_hand: None,
             vue_component_case: VueComponentCase::default(),
+            vue_custom_block_lang: [
+                ("i18n", "json"),
+                ("docs", "md"),
+                ("route", "json5"),
+                ("page-query", "graphql"),
+                ("static-query", "graphql"),
+            ]
+            .into_iter()
+            .map(|(name, lang)| (name.to_owned(), lang.to_owned()))
+            .collect(),
+            vue_attribute_order: false,
+            vue_expr_lang: None,
+            svelte_expr_lang: None,
             strict_svelte_attr: false,
             svelte_attr_shorthand: None,
             svelte_directive_shorthand: None,
             astro_attr_shorthand: None,
             angular_next_control_flow_same_line: true,
+            alpine: false,
+            thymeleaf: false,
+            json_attrs: vec![],
             script_formatter: None,
             ignore_comment_directive: "markup-fmt-ignore".into(),
             ignore_file_comment_directive: "markup-fmt-ignore-file".into(),
@@ -374,6 +412,28 @@ pub enum VueComponentCase {
     KebabCase,
 }
 
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "config_serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config_serde", serde(rename_all = "kebab-case"))]
+/// Language of expressions in templates.
+pub enum ExprLang {
+    Js,
+    Jsx,
+    Ts,
+    Tsx,
+}
+
+impl ExprLang {
+    pub(crate) fn ext(self) -> &'static str {
+        match self {
+            ExprLang::Js => "js",
+            ExprLang::Jsx => "jsx",
+            ExprLang::Ts => "ts",
+            ExprLang::Tsx => "tsx",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "config_serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "config_serde", serde(rename_all = "kebab-case"))]
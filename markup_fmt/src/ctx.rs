@@ -1,6 +1,7 @@
 use crate::{
     Language,
     config::{LanguageOptions, Quotes, WhitespaceSensitivity},
+    dialect::{self, Dialect},
     helpers,
     state::State,
 };
@@ -24,6 +25,8 @@ where
     pub(crate) indent_width: usize,
     pub(crate) print_width: usize,
     pub(crate) options: &'b LanguageOptions,
+    /// Extension passed to external formatter when formatting template expressions.
+    pub(crate) expr_ext: &'static str,
     pub(crate) external_formatter: F,
     pub(crate) external_formatter_errors: Vec<Error>,
 }
@@ -35,10 +38,22 @@ where
     pub(crate) fn script_indent(&self) -> bool {
         match self.language {
             Language::Html
+            | Language::Mdx
             | Language::Jinja
             | Language::Vento
             | Language::Angular
-            | Language::Mustache => self
+            | Language::Mustache
+            | Language::Glimmer
+            | Language::Liquid
+            | Language::Django
+            | Language::GoTemplate
+            | Language::Erb
+            | Language::Ejs
+            | Language::Php
+            | Language::Heex
+            | Language::Blade
+            | Language::Razor
+            | Language::Custom(..) => self
                 .options
                 .html_script_indent
                 .unwrap_or(self.options.script_indent),
@@ -61,10 +76,22 @@ where
     pub(crate) fn style_indent(&self) -> bool {
         match self.language {
             Language::Html
+            | Language::Mdx
             | Language::Jinja
             | Language::Vento
             | Language::Angular
-            | Language::Mustache => self
+            | Language::Mustache
+            | Language::Glimmer
+            | Language::Liquid
+            | Language::Django
+            | Language::GoTemplate
+            | Language::Erb
+            | Language::Ejs
+            | Language::Php
+            | Language::Heex
+            | Language::Blade
+            | Language::Razor
+            | Language::Custom(..) => self
                 .options
                 .html_style_indent
                 .unwrap_or(self.options.style_indent),
@@ -86,7 +113,11 @@ where
 
     pub(crate) fn is_whitespace_sensitive(&self, tag_name: &str) -> bool {
         match self.language {
-            Language::Vue | Language::Svelte | Language::Astro | Language::Angular
+            Language::Vue
+            | Language::Svelte
+            | Language::Astro
+            | Language::Mdx
+            | Language::Angular
                 if helpers::is_component(tag_name) =>
             {
                 matches!(
@@ -161,7 +192,7 @@ where
                     print_width: self.print_width,
                     indent_level: 0,
                     attr,
-                    ext: "tsx",
+                    ext: self.expr_ext,
                 },
                 start,
             )?;
@@ -357,13 +388,205 @@ where
         )
     }
 
-    pub(crate) fn format_jinja(
+    /// Formats JSON in attribute value.
+    pub(crate) fn try_format_json_attr(
         &mut self,
         code: &str,
         start: usize,
-        expr: bool,
+    ) -> Result<String, Error> {
+        self.try_format_with_external_formatter(
+            code.trim_ascii(),
+            Hints {
+                print_width: self.print_width,
+                indent_level: 0,
+                attr: true,
+                ext: "json",
+            },
+            start,
+        )
+        .map(|formatted| formatted.trim_ascii().to_owned())
+    }
+
+    pub(crate) fn format_markdown<'a>(
+        &mut self,
+        code: &'a str,
+        start: usize,
         state: &State,
+    ) -> Cow<'a, str> {
+        self.format_with_external_formatter(
+            code,
+            Hints {
+                print_width: self
+                    .print_width
+                    .saturating_sub((state.indent_level as usize) * self.indent_width),
+                indent_level: state.indent_level,
+                attr: false,
+                ext: "md",
+            },
+            start,
+        )
+    }
+
+    /// Formats PHP expression, or arguments of Blade directive.
+    /// It's wrapped as arguments of function call,
+    /// because external formatter expects a complete PHP file.
+    pub(crate) fn format_php_expr(&mut self, code: &str, start: usize, state: &State) -> String {
+        let code = code.trim_ascii();
+        if code.is_empty() {
+            return String::new();
+        }
+        let wrapped = format!("<?php _({code});");
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self
+                    .print_width
+                    .saturating_sub((state.indent_level as usize) * self.indent_width),
+                indent_level: state.indent_level,
+                attr: false,
+                ext: "php",
+            },
+            start,
+        );
+        formatted
+            .trim_ascii()
+            .strip_prefix("<?php")
+            .and_then(|s| s.trim_start().strip_prefix("_("))
+            .and_then(|s| s.trim_end().strip_suffix(';'))
+            .and_then(|s| s.trim_end().strip_suffix(')'))
+            .map(|s| s.trim_ascii())
+            .unwrap_or(code)
+            .to_owned()
+    }
+
+    pub(crate) fn format_php_stmt_header(
+        &mut self,
+        keyword: &str,
+        code: &str,
+        start: usize,
+    ) -> String {
+        let code = code.trim_ascii();
+        if code.is_empty() {
+            return String::new();
+        }
+        let wrapped = format!("<?php {keyword} ({code}) {{}}");
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self.print_width,
+                indent_level: 0,
+                attr: false,
+                ext: "php",
+            },
+            start,
+        );
+        formatted
+            .trim_ascii()
+            .strip_prefix("<?php")
+            .and_then(|s| s.trim_start().strip_prefix(keyword))
+            .and_then(|s| s.trim_start().strip_prefix('('))
+            .and_then(|s| s.trim_end().strip_suffix('}'))
+            .and_then(|s| s.trim_end().strip_suffix('{'))
+            .and_then(|s| s.trim_end().strip_suffix(')'))
+            .map(|s| s.trim_ascii())
+            .unwrap_or(code)
+            .to_owned()
+    }
+
+    /// Formats PHP statements in Blade `@php` block.
+    pub(crate) fn format_php_block(&mut self, code: &str, start: usize, state: &State) -> String {
+        if code.trim_ascii().is_empty() {
+            return String::new();
+        }
+        let wrapped = format!("<?php\n{code}");
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self
+                    .print_width
+                    .saturating_sub((state.indent_level as usize) * self.indent_width),
+                indent_level: state.indent_level,
+                attr: false,
+                ext: "php",
+            },
+            start,
+        );
+        let formatted = formatted.trim_ascii();
+        formatted
+            .strip_prefix("<?php")
+            .unwrap_or(formatted)
+            .trim_ascii()
+            .to_owned()
+    }
+
+    /// Formats C# expression in Razor `@(...)`.
+    /// It's wrapped as discard assignment to make it a valid statement.
+    pub(crate) fn format_cs_expr(&mut self, code: &str, start: usize, state: &State) -> String {
+        let code = code.trim_ascii();
+        if code.is_empty() {
+            return String::new();
+        }
+        let wrapped = format!("_ = {code};");
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self
+                    .print_width
+                    .saturating_sub((state.indent_level as usize) * self.indent_width),
+                indent_level: state.indent_level,
+                attr: false,
+                ext: "cs",
+            },
+            start,
+        );
+        formatted
+            .trim_ascii()
+            .strip_prefix('_')
+            .and_then(|s| s.trim_start().strip_prefix('='))
+            .and_then(|s| s.trim_end().strip_suffix(';'))
+            .map(|s| s.trim_ascii())
+            .unwrap_or(code)
+            .to_owned()
+    }
+
+    pub(crate) fn format_cs_stmt_header(
+        &mut self,
+        keyword: &str,
+        code: &str,
+        start: usize,
     ) -> String {
+        let code = code.trim_ascii();
+        if code.is_empty() {
+            return String::new();
+        }
+        let wrapped = format!("{keyword} ({code}) {{}}");
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self.print_width,
+                indent_level: 0,
+                attr: false,
+                ext: "cs",
+            },
+            start,
+        );
+        formatted
+            .trim_ascii()
+            .strip_prefix(keyword)
+            .and_then(|s| s.trim_start().strip_prefix('('))
+            .and_then(|s| s.trim_end().strip_suffix('}'))
+            .and_then(|s| s.trim_end().strip_suffix('{'))
+            .and_then(|s| s.trim_end().strip_suffix(')'))
+            .map(|s| s.trim_ascii())
+            .unwrap_or(code)
+            .to_owned()
+    }
+
+    /// Formats C# statements in Razor code block.
+    pub(crate) fn format_cs_block(&mut self, code: &str, start: usize, state: &State) -> String {
+        if code.trim_ascii().is_empty() {
+            return String::new();
+        }
         self.format_with_external_formatter(
             code,
             Hints {
@@ -372,11 +595,7 @@ where
                     .saturating_sub((state.indent_level as usize) * self.indent_width),
                 indent_level: state.indent_level,
                 attr: false,
-                ext: if expr {
-                    "markup-fmt-jinja-expr"
-                } else {
-                    "markup-fmt-jinja-stmt"
-                },
+                ext: "cs",
             },
             start,
         )
@@ -384,6 +603,59 @@ where
         .to_owned()
     }
 
+    /// Dialect for printing Jinja-like syntax, which falls back to Jinja.
+    pub(crate) fn jinja_dialect(&self) -> &'static dyn Dialect {
+        self.language.dialect().unwrap_or(&dialect::Jinja)
+    }
+
+    pub(crate) fn format_jinja(
+        &mut self,
+        code: &str,
+        start: usize,
+        expr: bool,
+        state: &State,
+    ) -> String {
+        let dialect = self.jinja_dialect();
+        let (expr_ext, stmt_ext) = dialect.external_formatter_exts();
+        let (prefix, suffix) = if expr {
+            Default::default()
+        } else {
+            let trimmed = code.trim_ascii();
+            match dialect.wrap_stmt(dialect.tag_name(trimmed), trimmed) {
+                Some(wrapper) => wrapper,
+                None => return trimmed.to_owned(),
+            }
+        };
+        let wrapped = if prefix.is_empty() && suffix.is_empty() {
+            Cow::from(code)
+        } else {
+            Cow::from(format!("{prefix}{}{suffix}", code.trim_ascii()))
+        };
+        let formatted = self.format_with_external_formatter(
+            &wrapped,
+            Hints {
+                print_width: self
+                    .print_width
+                    .saturating_sub((state.indent_level as usize) * self.indent_width),
+                indent_level: state.indent_level,
+                attr: false,
+                ext: if expr { expr_ext } else { stmt_ext },
+            },
+            start,
+        );
+        let formatted = formatted.trim_ascii();
+        if expr && matches!(expr_ext, "js" | "ts") {
+            // JavaScript formatters add semicolon after expression statement
+            formatted.trim_end_matches(';').trim_ascii_end().to_owned()
+        } else if prefix.is_empty() && suffix.is_empty() {
+            formatted.to_owned()
+        } else {
+            strip_wrapper(formatted, &prefix, &suffix)
+                .unwrap_or(code.trim_ascii())
+                .to_owned()
+        }
+    }
+
     fn format_with_external_formatter<'a>(
         &mut self,
         code: &'a str,
@@ -455,6 +727,22 @@ pub struct Hints<'s> {
     pub ext: &'s str,
 }
 
+/// Removes text added by [`Dialect::wrap_stmt`] from formatted code.
+/// Whitespaces are ignored, since they may be changed by external formatter.
+fn strip_wrapper<'a>(formatted: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    let mut rest = formatted;
+    for word in prefix.split_ascii_whitespace() {
+        rest = rest.strip_prefix(word)?.trim_ascii_start();
+    }
+    if !suffix.is_empty() {
+        rest = rest.trim_end_matches(';').trim_ascii_end();
+    }
+    for word in suffix.split_ascii_whitespace().rev() {
+        rest = rest.strip_suffix(word)?.trim_ascii_end();
+    }
+    Some(rest)
+}
+
 fn trim_delim<'a>(user_input: &str, formatted: &'a str, start: char, end: char) -> &'a str {
     if user_input
         .trim_start()
@@ -0,0 +1,824 @@
+//! Template dialects which share the structure of Jinja.
+//!
+//! Such template languages consist of interpolations, tags and comments
+//! wrapped by delimiters, and blocks formed by opening tags and closing tags.
+//! Only delimiters and block keywords vary among them.
+
+use std::fmt;
+
+/// Syntax description of a Jinja-like template language.
+///
+/// Built-in [`Jinja`] dialect is used for [`Language::Jinja`](crate::Language::Jinja).
+/// For other dialects, implement this trait
+/// then format with [`Language::Custom`](crate::Language::Custom):
+///
+/// ```
+/// use markup_fmt::{dialect::Dialect, format_text, Language};
+///
+/// struct Brackets;
+///
+/// impl Dialect for Brackets {
+///     fn name(&self) -> &str {
+///         "brackets"
+///     }
+///     fn interpolation(&self) -> (&str, &str) {
+///         ("[[", "]]")
+///     }
+///     fn tag(&self) -> (&str, &str) {
+///         ("[%", "%]")
+///     }
+///     fn comment(&self) -> (&str, &str) {
+///         ("[#", "#]")
+///     }
+///     fn is_block_start(&self, tag_name: &str, _: &str) -> bool {
+///         matches!(tag_name, "if" | "for")
+///     }
+///     fn is_block_branch(&self, tag_name: &str, _: &str) -> bool {
+///         tag_name == "else"
+///     }
+/// }
+///
+/// static BRACKETS: Brackets = Brackets;
+///
+/// let formatted = format_text(
+///     "<ul>[% for item in items %]<li>[[item]]</li>[% endfor %]</ul>",
+///     Language::Custom(&BRACKETS),
+///     &Default::default(),
+///     |code, _| Ok(code.into()),
+/// ).unwrap();
+/// assert_eq!(
+///     formatted,
+///     "<ul>[% for item in items %]<li>[[ item ]]</li>[% endfor %]</ul>\n",
+/// );
+/// ```
+pub trait Dialect: Sync {
+    /// Name of this dialect. Dialects with the same name are treated as equal.
+    fn name(&self) -> &str;
+
+    /// Opening and closing delimiters of interpolation, such as `{{` and `}}`.
+    fn interpolation(&self) -> (&str, &str);
+
+    /// Opening and closing delimiters of tag, such as `{%` and `%}`.
+    fn tag(&self) -> (&str, &str);
+
+    /// Opening and closing delimiters of comment, such as `{#` and `#}`.
+    ///
+    /// Return empty strings if this dialect doesn't have dedicated comment syntax.
+    fn comment(&self) -> (&str, &str);
+
+    /// Characters which can be placed right after opening delimiter
+    /// or right before closing delimiter to control whitespace, such as `-` in `{%-`.
+    fn trim_markers(&self) -> &[char] {
+        &['-', '+']
+    }
+
+    /// Escaped delimiters which are plain text, such as `<%%` in ERB.
+    /// Opening delimiters aren't recognized where these escapes start.
+    fn escapes(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Marker which can be placed right after opening delimiter of tag
+    /// to output unescaped value, such as `-` in EJS `<%- html %>`.
+    /// Tags with this marker are formatted as expressions.
+    fn raw_output_marker(&self) -> Option<&str> {
+        None
+    }
+
+    /// Extracts tag name from the whole content of a tag without delimiters.
+    /// By default, it's the first word after trim marker.
+    ///
+    /// Tags opened by interpolation delimiter can start blocks as well, such as `<%= form_with do %>`,
+    /// then the rest of interpolation delimiter (`=` in that example) is skipped, too.
+    ///
+    /// Other methods that accept `tag_name` or `block_name` receive the result of this method.
+    fn tag_name<'a>(&self, content: &'a str) -> &'a str {
+        let content = self
+            .interpolation()
+            .0
+            .strip_prefix(self.tag().0)
+            .filter(|marker| !marker.is_empty())
+            .and_then(|marker| content.strip_prefix(marker))
+            .unwrap_or(content);
+        let trimmed = content.trim_start_matches(self.trim_markers()).trim_start();
+        trimmed
+            .split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .map(|(name, _)| name)
+            .unwrap_or(trimmed)
+    }
+
+    /// Checks if a tag opens a block which will be closed by another tag.
+    ///
+    /// `content` is the whole content of that tag without delimiters.
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool;
+
+    /// Checks if a tag closes the block which is opened by `block_name`.
+    fn is_block_end(&self, tag_name: &str, block_name: &str) -> bool {
+        tag_name
+            .strip_prefix("end")
+            .is_some_and(|name| name == block_name)
+    }
+
+    /// Checks if a tag starts another branch of the block which is opened by `block_name`,
+    /// such as `else`.
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool;
+
+    /// Checks if content of the block opened by `block_name` should be kept as is,
+    /// such as `raw` block.
+    fn is_raw_block(&self, block_name: &str) -> bool {
+        let _ = block_name;
+        false
+    }
+
+    /// File extension of code inside the block opened by `block_name`,
+    /// if that code should be formatted by external formatter as a whole.
+    fn embedded_code(&self, block_name: &str) -> Option<&str> {
+        let _ = block_name;
+        None
+    }
+
+    /// File extensions passed to external formatter
+    /// for formatting expressions in interpolations and statements in tags.
+    /// Template languages without standalone formatters use fake extensions.
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("markup-fmt-jinja-expr", "markup-fmt-jinja-stmt")
+    }
+
+    /// Wraps code of tag which is only a part of block, such as `if a` or `} else {`,
+    /// so the external formatter receives complete code.
+    ///
+    /// Returns text inserted before and after the code, which is removed after formatting,
+    /// or `None` if the code shouldn't be formatted.
+    fn wrap_stmt(&self, tag_name: &str, code: &str) -> Option<(String, String)> {
+        let _ = (tag_name, code);
+        Some(Default::default())
+    }
+}
+
+impl fmt::Debug for dyn Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Dialect").field(&self.name()).finish()
+    }
+}
+
+impl PartialEq for dyn Dialect {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+impl Eq for dyn Dialect {}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Jinja, Twig and Nunjucks.
+///
+/// See https://jinja.palletsprojects.com/en/stable/templates/.
+pub struct Jinja;
+
+impl Dialect for Jinja {
+    fn name(&self) -> &str {
+        "jinja"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("{{", "}}")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("{%", "%}")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("{#", "#}")
+    }
+
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool {
+        matches!(
+            tag_name,
+            "for"
+                | "if"
+                | "macro"
+                | "call"
+                | "filter"
+                | "block"
+                | "apply"
+                | "autoescape"
+                | "embed"
+                | "with"
+                | "trans"
+                | "raw"
+        ) || tag_name == "set" && !content.contains('=')
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        (block_name == "if" || block_name == "for")
+            && matches!(tag_name, "elif" | "elseif" | "else")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Liquid used by Shopify and Jekyll.
+///
+/// See https://shopify.github.io/liquid/.
+pub struct Liquid;
+
+impl Dialect for Liquid {
+    fn name(&self) -> &str {
+        "liquid"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("{{", "}}")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("{%", "%}")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("", "")
+    }
+
+    fn is_block_start(&self, tag_name: &str, _: &str) -> bool {
+        matches!(
+            tag_name,
+            "if" | "unless"
+                | "case"
+                | "for"
+                | "tablerow"
+                | "capture"
+                | "paginate"
+                | "form"
+                | "comment"
+                | "doc"
+                | "raw"
+                | "schema"
+                | "style"
+                | "stylesheet"
+                | "javascript"
+        )
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        match block_name {
+            "if" | "unless" => matches!(tag_name, "elsif" | "else"),
+            "case" => matches!(tag_name, "when" | "else"),
+            "for" => tag_name == "else",
+            _ => false,
+        }
+    }
+
+    fn is_raw_block(&self, block_name: &str) -> bool {
+        matches!(block_name, "comment" | "doc" | "raw")
+    }
+
+    fn embedded_code(&self, block_name: &str) -> Option<&str> {
+        match block_name {
+            "schema" => Some("json"),
+            "style" | "stylesheet" => Some("css"),
+            "javascript" => Some("js"),
+            _ => None,
+        }
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("markup-fmt-liquid-expr", "markup-fmt-liquid-stmt")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Django template language.
+///
+/// See https://docs.djangoproject.com/en/stable/ref/templates/language/.
+pub struct Django;
+
+impl Dialect for Django {
+    fn name(&self) -> &str {
+        "django"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("{{", "}}")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("{%", "%}")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("{#", "#}")
+    }
+
+    fn is_block_start(&self, tag_name: &str, _: &str) -> bool {
+        matches!(
+            tag_name,
+            "if" | "for"
+                | "block"
+                | "with"
+                | "filter"
+                | "autoescape"
+                | "spaceless"
+                | "ifchanged"
+                | "ifequal"
+                | "ifnotequal"
+                | "localize"
+                | "localtime"
+                | "timezone"
+                | "language"
+                | "cache"
+                | "blocktrans"
+                | "blocktranslate"
+                | "comment"
+                | "verbatim"
+        )
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        match block_name {
+            "if" => matches!(tag_name, "elif" | "else"),
+            "for" => tag_name == "empty",
+            "ifchanged" | "ifequal" | "ifnotequal" => tag_name == "else",
+            _ => false,
+        }
+    }
+
+    fn is_raw_block(&self, block_name: &str) -> bool {
+        // whitespace inside `blocktrans` is part of the message id
+        matches!(
+            block_name,
+            "comment" | "verbatim" | "blocktrans" | "blocktranslate"
+        )
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("markup-fmt-django-expr", "markup-fmt-django-stmt")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Go `text/template` and `html/template`, also used by Hugo.
+///
+/// Actions share the same delimiters, so all of them are treated as tags.
+/// Comments are actions as well, since they can have trim markers like `{{- /* comment */ -}}`.
+///
+/// See https://pkg.go.dev/text/template.
+pub struct GoTemplate;
+
+impl Dialect for GoTemplate {
+    fn name(&self) -> &str {
+        "go-template"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("{{", "}}")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("{{", "}}")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("", "")
+    }
+
+    fn trim_markers(&self) -> &[char] {
+        &['-']
+    }
+
+    fn is_block_start(&self, tag_name: &str, _: &str) -> bool {
+        matches!(tag_name, "if" | "range" | "with" | "define" | "block")
+    }
+
+    fn is_block_end(&self, tag_name: &str, _: &str) -> bool {
+        tag_name == "end"
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        matches!(block_name, "if" | "range" | "with") && tag_name == "else"
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("markup-fmt-go-template-expr", "markup-fmt-go-template-stmt")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// ERB, embedded Ruby used by Rails.
+///
+/// See https://github.com/ruby/erb.
+pub struct Erb;
+
+impl Dialect for Erb {
+    fn name(&self) -> &str {
+        "erb"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("<%=", "%>")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("<%", "%>")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("<%#", "%>")
+    }
+
+    fn escapes(&self) -> &[&str] {
+        &["<%%", "%%>"]
+    }
+
+    fn trim_markers(&self) -> &[char] {
+        &['-']
+    }
+
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool {
+        let code = content.trim_matches(self.trim_markers()).trim();
+        if code
+            .rsplit(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .next()
+            .is_some_and(|word| word == "end")
+        {
+            // one-line statement like `if a then b end`
+            return false;
+        }
+        if matches!(
+            tag_name,
+            "if" | "unless" | "case" | "while" | "until" | "for" | "begin"
+        ) {
+            return true;
+        }
+        // block with parameters, like `items.each do |item|`
+        let code = code
+            .strip_suffix('|')
+            .and_then(|code| code.rsplit_once('|'))
+            .map_or(code, |(code, _)| code.trim_end());
+        code.strip_suffix("do").is_some_and(|code| {
+            code.is_empty() || code.ends_with(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        })
+    }
+
+    fn is_block_end(&self, tag_name: &str, _: &str) -> bool {
+        tag_name == "end"
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        match block_name {
+            "if" | "unless" => matches!(tag_name, "elsif" | "else"),
+            "case" => matches!(tag_name, "when" | "in" | "else"),
+            "begin" => matches!(tag_name, "rescue" | "else" | "ensure"),
+            _ => matches!(tag_name, "rescue" | "ensure"),
+        }
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("rb", "rb")
+    }
+
+    fn wrap_stmt(&self, tag_name: &str, code: &str) -> Option<(String, String)> {
+        let prefix = match tag_name {
+            "end" => return None,
+            "elsif" | "else" => "if _\n",
+            "when" | "in" => "case _\n",
+            "rescue" | "ensure" => "begin\n",
+            _ if self.is_block_start(tag_name, code) => "",
+            _ => return Some(Default::default()),
+        };
+        Some((prefix.into(), "\nend".into()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// EJS, embedded JavaScript templates.
+///
+/// Blocks are formed by braces, so tag name of `} else {` is `else`
+/// and tag name of `}` is `}`.
+///
+/// See https://ejs.co/.
+pub struct Ejs;
+
+impl Dialect for Ejs {
+    fn name(&self) -> &str {
+        "ejs"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("<%=", "%>")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("<%", "%>")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("<%#", "%>")
+    }
+
+    fn escapes(&self) -> &[&str] {
+        &["<%%", "%%>"]
+    }
+
+    fn trim_markers(&self) -> &[char] {
+        &['-', '_']
+    }
+
+    fn tag_name<'a>(&self, content: &'a str) -> &'a str {
+        let code = content.trim_matches(self.trim_markers()).trim();
+        if let Some(rest) = code.strip_prefix('}') {
+            let rest = rest.trim_start();
+            if rest.ends_with('{') {
+                rest.split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .map_or(rest, |(name, _)| name)
+            } else {
+                &code[..1]
+            }
+        } else {
+            code.split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .map_or(code, |(name, _)| name)
+        }
+    }
+
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool {
+        tag_name != "}"
+            && !self.is_block_branch(tag_name, "")
+            && content
+                .trim_matches(self.trim_markers())
+                .trim()
+                .ends_with('{')
+    }
+
+    fn is_block_end(&self, tag_name: &str, _: &str) -> bool {
+        tag_name == "}"
+    }
+
+    fn is_block_branch(&self, tag_name: &str, _: &str) -> bool {
+        matches!(tag_name, "else" | "catch" | "finally")
+    }
+
+    fn raw_output_marker(&self) -> Option<&str> {
+        Some("-")
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("js", "js")
+    }
+
+    fn wrap_stmt(&self, tag_name: &str, code: &str) -> Option<(String, String)> {
+        match tag_name {
+            "}" => None,
+            "else" => Some(("if (_) {\n".into(), "\n}".into())),
+            "catch" | "finally" => Some(("try {\n".into(), "\n}".into())),
+            _ => {
+                // close brackets left open by block start, like `items.forEach((item) => {`
+                let mut closing = String::new();
+                let mut quote = None;
+                let mut chars = code.chars();
+                while let Some(c) = chars.next() {
+                    match (quote, c) {
+                        (Some(_), '\\') => {
+                            chars.next();
+                        }
+                        (Some(q), c) if c == q => quote = None,
+                        (Some(_), _) => {}
+                        (None, '\'' | '"' | '`') => quote = Some(c),
+                        (None, '(') => closing.push(')'),
+                        (None, '[') => closing.push(']'),
+                        (None, '{') => closing.push('}'),
+                        (None, ')' | ']' | '}') => {
+                            closing.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                if closing.is_empty() {
+                    Some(Default::default())
+                } else {
+                    Some((
+                        String::new(),
+                        format!("\n{}", closing.chars().rev().collect::<String>()),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// HEEx, HTML-aware embedded Elixir used by Phoenix.
+///
+/// Blocks are opened by tags ending with `do`, and closed by `<% end %>`.
+/// Clauses in `case` or `cond` blocks end with `->`, so their tag name is `->`.
+///
+/// See https://hexdocs.pm/phoenix_live_view/Phoenix.Component.html#sigil_H/2.
+pub struct Heex;
+
+impl Dialect for Heex {
+    fn name(&self) -> &str {
+        "heex"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("<%=", "%>")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("<%", "%>")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("<%!--", "--%>")
+    }
+
+    fn trim_markers(&self) -> &[char] {
+        &[]
+    }
+
+    fn tag_name<'a>(&self, content: &'a str) -> &'a str {
+        let code = content.strip_prefix('=').unwrap_or(content).trim();
+        if code.ends_with("->") {
+            &code[code.len() - 2..]
+        } else {
+            code.split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .map_or(code, |(name, _)| name)
+        }
+    }
+
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool {
+        tag_name != "->"
+            && content
+                .trim_end()
+                .strip_suffix("do")
+                .is_some_and(|code| code.ends_with(|c: char| c.is_ascii_whitespace() || c == ')'))
+    }
+
+    fn is_block_end(&self, tag_name: &str, _: &str) -> bool {
+        tag_name == "end"
+    }
+
+    fn is_block_branch(&self, tag_name: &str, _: &str) -> bool {
+        matches!(tag_name, "else" | "->")
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("ex", "markup-fmt-heex-stmt")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// PHP code embedded in HTML, such as WordPress themes.
+///
+/// Blocks are formed either by alternative syntax like `<?php if ($a): ?>` and `<?php endif; ?>`,
+/// or by braces like `<?php if ($a) { ?>` and `<?php } ?>`.
+/// Same as [`Ejs`], tag name of `} else {` is `else` and tag name of `}` is `}`.
+///
+/// See https://www.php.net/manual/en/control-structures.alternative-syntax.php.
+pub struct Php;
+
+impl Dialect for Php {
+    fn name(&self) -> &str {
+        "php"
+    }
+
+    fn interpolation(&self) -> (&str, &str) {
+        ("<?=", "?>")
+    }
+
+    fn tag(&self) -> (&str, &str) {
+        ("<?php", "?>")
+    }
+
+    fn comment(&self) -> (&str, &str) {
+        ("", "")
+    }
+
+    fn trim_markers(&self) -> &[char] {
+        &[]
+    }
+
+    fn tag_name<'a>(&self, content: &'a str) -> &'a str {
+        let code = content.trim();
+        if let Some(rest) = code.strip_prefix('}') {
+            let rest = rest.trim_start();
+            if rest.ends_with('{') {
+                rest.split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .map_or(rest, |(name, _)| name)
+            } else {
+                &code[..1]
+            }
+        } else {
+            code.split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .map_or(code, |(name, _)| name)
+        }
+    }
+
+    fn is_block_start(&self, tag_name: &str, content: &str) -> bool {
+        let code = content.trim();
+        if matches!(
+            tag_name,
+            "if" | "foreach" | "for" | "while" | "switch" | "declare"
+        ) {
+            // statements can follow the condition, like `while (have_posts()): the_post();`
+            let Some(condition) = code.strip_prefix(tag_name) else {
+                return false;
+            };
+            let condition = condition.trim_start();
+            let mut depth = 0usize;
+            let rest = condition
+                .char_indices()
+                .find_map(|(i, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => {
+                            depth = depth.saturating_sub(1);
+                            if depth == 0 {
+                                return Some(i + 1);
+                            }
+                        }
+                        _ => {}
+                    }
+                    None
+                })
+                .map_or("", |i| condition[i..].trim_start());
+            rest.starts_with(':')
+                || rest.starts_with('{') && rest.matches('{').count() > rest.matches('}').count()
+        } else {
+            tag_name != "}" && !self.is_block_branch(tag_name, "") && code.ends_with('{')
+        }
+    }
+
+    fn is_block_end(&self, tag_name: &str, block_name: &str) -> bool {
+        tag_name == "}"
+            || tag_name
+                .strip_prefix("end")
+                .is_some_and(|name| name == block_name)
+    }
+
+    fn is_block_branch(&self, tag_name: &str, block_name: &str) -> bool {
+        match block_name {
+            "switch" => matches!(tag_name, "case" | "default"),
+            _ => matches!(tag_name, "elseif" | "else" | "catch" | "finally"),
+        }
+    }
+
+    fn external_formatter_exts(&self) -> (&str, &str) {
+        ("markup-fmt-php-expr", "markup-fmt-php-stmt")
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum DialectDelimiter {
+    Interpolation,
+    Tag,
+    Comment,
+}
+
+impl DialectDelimiter {
+    pub(crate) fn of(self, dialect: &dyn Dialect) -> (&str, &str) {
+        match self {
+            DialectDelimiter::Interpolation => dialect.interpolation(),
+            DialectDelimiter::Tag => dialect.tag(),
+            DialectDelimiter::Comment => dialect.comment(),
+        }
+    }
+}
+
+/// Returns the marker if tag outputs a value,
+/// like `=` in `<%= form_with do %>` or `-` in EJS `<%- html %>`.
+pub(crate) fn output_marker<'d>(dialect: &'d dyn Dialect, content: &str) -> Option<&'d str> {
+    dialect
+        .interpolation()
+        .0
+        .strip_prefix(dialect.tag().0)
+        .filter(|marker| !marker.is_empty() && content.starts_with(marker))
+        .or_else(|| {
+            dialect
+                .raw_output_marker()
+                .filter(|marker| content.starts_with(marker))
+        })
+}
+
+/// Checks which kind of opening delimiter the text starts with.
+/// Longer delimiter takes precedence if there're multiple matches,
+/// then comment takes precedence over tag, and tag over interpolation.
+pub(crate) fn match_opening_delimiter(
+    dialect: &dyn Dialect,
+    text: &str,
+) -> Option<DialectDelimiter> {
+    if dialect
+        .escapes()
+        .iter()
+        .any(|escape| text.starts_with(escape))
+    {
+        return None;
+    }
+    [
+        (dialect.interpolation().0, DialectDelimiter::Interpolation),
+        (dialect.tag().0, DialectDelimiter::Tag),
+        (dialect.comment().0, DialectDelimiter::Comment),
+    ]
+    .into_iter()
+    .filter(|(open, _)| !open.is_empty() && text.starts_with(open))
+    .max_by_key(|(open, _)| open.len())
+    .map(|(_, kind)| kind)
+}
@@ -19,6 +19,13 @@ pub enum SyntaxErrorKind {
     ExpectAstroExpr,
     ExpectAttrName,
     ExpectAttrValue,
+    ExpectBladeBlockEnd {
+        directive: String,
+        line: usize,
+        column: usize,
+    },
+    ExpectBladeDirective,
+    ExpectBladeEcho,
     ExpectCdata,
     ExpectChar(char),
     ExpectCloseTag {
@@ -30,6 +37,7 @@ pub enum SyntaxErrorKind {
     ExpectDoctype,
     ExpectElement,
     ExpectFrontMatter,
+    ExpectHeexAttr,
     ExpectIdentifier,
     ExpectJinjaBlockEnd {
         tag_name: String,
@@ -38,7 +46,11 @@ pub enum SyntaxErrorKind {
     },
     ExpectJinjaTag,
     ExpectKeyword(&'static str),
+    ExpectMdxFlow,
     ExpectMustacheInterpolation,
+    ExpectRazorBlock,
+    ExpectRazorComment,
+    ExpectRazorExpr,
     ExpectSelfCloseTag,
     ExpectSvelteAttachment,
     ExpectSvelteAtTag,
@@ -72,6 +84,16 @@ impl fmt::Display for SyntaxErrorKind {
             SyntaxErrorKind::ExpectAstroExpr => "expected Astro expression".into(),
             SyntaxErrorKind::ExpectAttrName => "expected attribute name".into(),
             SyntaxErrorKind::ExpectAttrValue => "expected attribute value".into(),
+            SyntaxErrorKind::ExpectBladeBlockEnd {
+                directive,
+                line,
+                column,
+            } => format!(
+                "expected end directive for opening Blade directive @{directive} from line {line}, column {column}"
+            )
+            .into(),
+            SyntaxErrorKind::ExpectBladeDirective => "expected Blade directive".into(),
+            SyntaxErrorKind::ExpectBladeEcho => "expected Blade echo".into(),
             SyntaxErrorKind::ExpectCdata => "expected CDATA section".into(),
             SyntaxErrorKind::ExpectChar(c) => format!("expected char '{c}'").into(),
             SyntaxErrorKind::ExpectCloseTag {
@@ -86,6 +108,7 @@ impl fmt::Display for SyntaxErrorKind {
             SyntaxErrorKind::ExpectDoctype => "expected HTML doctype".into(),
             SyntaxErrorKind::ExpectElement => "expected element".into(),
             SyntaxErrorKind::ExpectFrontMatter => "expected front matter".into(),
+            SyntaxErrorKind::ExpectHeexAttr => "expected HEEx attribute".into(),
             SyntaxErrorKind::ExpectIdentifier => "expected identifier".into(),
             SyntaxErrorKind::ExpectJinjaBlockEnd {
                 tag_name,
@@ -99,9 +122,13 @@ impl fmt::Display for SyntaxErrorKind {
             SyntaxErrorKind::ExpectKeyword(keyword) => {
                 format!("expected keyword '{keyword}'").into()
             }
+            SyntaxErrorKind::ExpectMdxFlow => "expected MDX JSX block or expression".into(),
             SyntaxErrorKind::ExpectMustacheInterpolation => {
                 "expected mustache-like interpolation".into()
             }
+            SyntaxErrorKind::ExpectRazorBlock => "expected Razor code block".into(),
+            SyntaxErrorKind::ExpectRazorComment => "expected Razor comment".into(),
+            SyntaxErrorKind::ExpectRazorExpr => "expected Razor expression".into(),
             SyntaxErrorKind::ExpectSelfCloseTag => "expected self close tag".into(),
             SyntaxErrorKind::ExpectSvelteAttachment => "expected Svelte attachment".into(),
             SyntaxErrorKind::ExpectSvelteAtTag => "expected Svelte `{@` tag".into(),
@@ -1,6 +1,13 @@
-use crate::Language;
+use crate::{
+    Language,
+    ast::{Attribute, Node, NodeKind},
+    config::{ExprLang, LanguageOptions},
+};
 use aho_corasick::AhoCorasick;
-use std::{borrow::Cow, cmp::Ordering, ops::ControlFlow, sync::LazyLock};
+use std::{
+    borrow::Cow, cmp::Ordering, iter::Peekable, mem, ops::ControlFlow, str::CharIndices,
+    sync::LazyLock,
+};
 
 pub(crate) fn is_component(name: &str) -> bool {
     name.contains('-') || name.contains(|c: char| c.is_ascii_uppercase())
@@ -87,7 +94,21 @@ static NON_WS_SENSITIVE_TAGS: [&str; 76] = [
 
 pub(crate) fn is_whitespace_sensitive_tag(name: &str, language: Language) -> bool {
     match language {
-        Language::Html | Language::Jinja | Language::Vento | Language::Mustache => {
+        Language::Html
+        | Language::Jinja
+        | Language::Vento
+        | Language::Mustache
+        | Language::Glimmer
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Blade
+        | Language::Razor
+        | Language::Custom(..) => {
             // There's also a tag called "a" in SVG, so we need to check it specially.
             name.eq_ignore_ascii_case("a")
                 || !NON_WS_SENSITIVE_TAGS
@@ -113,7 +134,21 @@ static VOID_ELEMENTS: [&str; 14] = [
 
 pub(crate) fn is_void_element(name: &str, language: Language) -> bool {
     match language {
-        Language::Html | Language::Jinja | Language::Vento | Language::Mustache => VOID_ELEMENTS
+        Language::Html
+        | Language::Jinja
+        | Language::Vento
+        | Language::Mustache
+        | Language::Glimmer
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Blade
+        | Language::Razor
+        | Language::Custom(..) => VOID_ELEMENTS
             .iter()
             .any(|tag| tag.eq_ignore_ascii_case(name)),
         Language::Xml => false,
@@ -123,7 +158,21 @@ pub(crate) fn is_void_element(name: &str, language: Language) -> bool {
 
 pub(crate) fn is_html_tag(name: &str, language: Language) -> bool {
     match language {
-        Language::Html | Language::Jinja | Language::Vento | Language::Mustache => {
+        Language::Html
+        | Language::Jinja
+        | Language::Vento
+        | Language::Mustache
+        | Language::Glimmer
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Blade
+        | Language::Razor
+        | Language::Custom(..) => {
             css_dataset::tags::STANDARD_HTML_TAGS
                 .iter()
                 .any(|tag| tag.eq_ignore_ascii_case(name))
@@ -142,7 +191,21 @@ pub(crate) fn is_html_tag(name: &str, language: Language) -> bool {
 pub(crate) fn is_svg_tag(name: &str, language: Language) -> bool {
     if matches!(
         language,
-        Language::Html | Language::Jinja | Language::Vento | Language::Mustache
+        Language::Html
+            | Language::Jinja
+            | Language::Vento
+            | Language::Mustache
+            | Language::Glimmer
+            | Language::Liquid
+            | Language::Django
+            | Language::GoTemplate
+            | Language::Erb
+            | Language::Ejs
+            | Language::Php
+            | Language::Heex
+            | Language::Blade
+            | Language::Razor
+            | Language::Custom(..)
     ) {
         css_dataset::tags::SVG_TAGS
             .iter()
@@ -154,11 +217,23 @@ pub(crate) fn is_svg_tag(name: &str, language: Language) -> bool {
 
 pub(crate) fn is_mathml_tag(name: &str, language: Language) -> bool {
     match language {
-        Language::Html | Language::Jinja | Language::Vento | Language::Mustache => {
-            css_dataset::tags::MATH_ML_TAGS
-                .iter()
-                .any(|tag| tag.eq_ignore_ascii_case(name))
-        }
+        Language::Html
+        | Language::Jinja
+        | Language::Vento
+        | Language::Mustache
+        | Language::Glimmer
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Blade
+        | Language::Razor
+        | Language::Custom(..) => css_dataset::tags::MATH_ML_TAGS
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case(name)),
         Language::Xml => false,
         _ => css_dataset::tags::MATH_ML_TAGS.contains(&name),
     }
@@ -171,6 +246,544 @@ pub(crate) fn parse_vento_tag(tag: &str) -> (&str, &str) {
         .unwrap_or((trimmed, ""))
 }
 
+/// Checks if a Blade directive without arguments is known,
+/// so it won't be confused with plain text like `@username`.
+pub(crate) fn is_blade_directive_name(name: &str) -> bool {
+    name.starts_with("end")
+        || matches!(
+            name,
+            "else"
+                | "empty"
+                | "default"
+                | "break"
+                | "continue"
+                | "csrf"
+                | "auth"
+                | "guest"
+                | "production"
+                | "once"
+                | "php"
+                | "verbatim"
+                | "parent"
+                | "show"
+                | "stop"
+                | "append"
+                | "overwrite"
+                | "livewireStyles"
+                | "livewireScripts"
+        )
+}
+
+/// Checks if a Blade directive with arguments is known,
+/// so text like `email me @home(ish)` won't be treated as directive.
+pub(crate) fn is_blade_directive_with_args(name: &str) -> bool {
+    name.starts_with("include")
+        || name.starts_with("push")
+        || name.starts_with("prepend")
+        || name.starts_with("can")
+        || name.starts_with("vite")
+        || matches!(
+            name,
+            "if" | "elseif"
+                | "unless"
+                | "isset"
+                | "empty"
+                | "auth"
+                | "guest"
+                | "env"
+                | "production"
+                | "hasSection"
+                | "sectionMissing"
+                | "switch"
+                | "case"
+                | "for"
+                | "foreach"
+                | "forelse"
+                | "while"
+                | "break"
+                | "continue"
+                | "php"
+                | "extends"
+                | "section"
+                | "yield"
+                | "stack"
+                | "hasstack"
+                | "each"
+                | "once"
+                | "error"
+                | "component"
+                | "slot"
+                | "props"
+                | "aware"
+                | "fragment"
+                | "session"
+                | "context"
+                | "class"
+                | "style"
+                | "checked"
+                | "selected"
+                | "disabled"
+                | "readonly"
+                | "required"
+                | "method"
+                | "json"
+                | "js"
+                | "lang"
+                | "choice"
+                | "inject"
+                | "use"
+                | "dd"
+                | "dump"
+                | "livewire"
+                | "persist"
+                | "teleport"
+                | "entangle"
+                | "this"
+        )
+}
+
+/// Checks if a Blade directive opens a block which will be closed by another directive.
+pub(crate) fn is_blade_block_start(name: &str, args: Option<&str>) -> bool {
+    match name {
+        "if" | "unless" | "isset" | "auth" | "guest" | "production" | "env" | "hasSection"
+        | "sectionMissing" | "switch" | "for" | "foreach" | "forelse" | "while" | "push"
+        | "prepend" | "pushOnce" | "prependOnce" | "pushIf" | "once" | "can" | "cannot"
+        | "canany" | "error" | "component" | "fragment" | "session" | "verbatim" => true,
+        // `@empty` without arguments is a branch of `@forelse`
+        "empty" => args.is_some(),
+        // `@php($value = 1)` is a statement
+        "php" => args.is_none(),
+        // `@section('title', 'Page Title')` is a statement
+        "section" | "slot" => args.is_some_and(|args| {
+            let mut depth = 0u16;
+            let mut quote = None;
+            let mut escaped = false;
+            !args.chars().any(|c| {
+                if let Some(q) = quote {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if c == q {
+                        quote = None;
+                    }
+                    return false;
+                }
+                match c {
+                    '\'' | '"' => quote = Some(c),
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                    ',' => return depth == 0,
+                    _ => {}
+                }
+                false
+            })
+        }),
+        _ => false,
+    }
+}
+
+/// Checks if a Blade directive closes the block which is opened by `block_name`.
+pub(crate) fn is_blade_block_end(name: &str, block_name: &str) -> bool {
+    match block_name {
+        "hasSection" | "sectionMissing" => name == "endif",
+        "section" => matches!(
+            name,
+            "endsection" | "show" | "stop" | "append" | "overwrite"
+        ),
+        _ => name
+            .strip_prefix("end")
+            .is_some_and(|name| name.eq_ignore_ascii_case(block_name)),
+    }
+}
+
+/// Checks if a Blade directive starts another branch of the block which is opened by `block_name`.
+pub(crate) fn is_blade_block_branch(name: &str, block_name: &str) -> bool {
+    match block_name {
+        "if" | "unless" | "isset" | "empty" | "auth" | "guest" | "production" | "env"
+        | "hasSection" | "sectionMissing" => matches!(name, "elseif" | "else"),
+        "can" | "cannot" | "canany" => {
+            name == "else"
+                || name
+                    .strip_prefix("else")
+                    .is_some_and(|name| name == block_name)
+        }
+        "forelse" => name == "empty",
+        "switch" => matches!(name, "case" | "default"),
+        _ => false,
+    }
+}
+
+/// Razor directives which take the rest of line, such as `@model` and `@inject`.
+pub(crate) fn is_razor_directive_name(name: &str) -> bool {
+    matches!(
+        name,
+        "model"
+            | "using"
+            | "inject"
+            | "page"
+            | "layout"
+            | "inherits"
+            | "implements"
+            | "namespace"
+            | "attribute"
+            | "typeparam"
+            | "rendermode"
+            | "preservewhitespace"
+            | "addTagHelper"
+            | "removeTagHelper"
+            | "tagHelperPrefix"
+    )
+}
+
+/// Checks if C# code in Razor code block contains markup,
+/// such as `<p>...</p>`, `<text>...</text>` or `@:` line transition.
+pub(crate) fn razor_code_has_markup(code: &str) -> bool {
+    let mut chars = code.chars().peekable();
+    // last character which isn't whitespace, comment or string
+    let mut prev = None;
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('/', Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '*' && chars.next_if_eq(&'/').is_some() {
+                        break;
+                    }
+                }
+            }
+            ('@', Some('"')) => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '"' && chars.next_if_eq(&'"').is_none() {
+                        break;
+                    }
+                }
+                prev = Some('"');
+            }
+            ('@', Some(':' | '<')) => return true,
+            ('"' | '\'', _) => {
+                while let Some(next) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                    } else if next == c || next == '\n' {
+                        break;
+                    }
+                }
+                prev = Some(c);
+            }
+            ('<', Some(next))
+                if (next.is_ascii_alphabetic() || *next == '/')
+                    && matches!(prev, None | Some('{' | '}' | ';' | ':')) =>
+            {
+                return true;
+            }
+            (c, _) if c.is_whitespace() => {}
+            (c, _) => prev = Some(c),
+        }
+    }
+    false
+}
+
+/// Extracts the comment from Go template action, like `{{/* comment */}}` or `{{- /* comment */ -}}`.
+pub(crate) fn go_template_comment(action: &str) -> Option<&str> {
+    let action = action.strip_prefix('-').unwrap_or(action).trim_start();
+    let action = action.trim_end();
+    let action = action.strip_suffix('-').unwrap_or(action).trim_end();
+    action.strip_prefix("/*")?.strip_suffix("*/")
+}
+
+/// Normalizes whitespaces in Go template action,
+/// so arguments are separated by one space and pipes are surrounded by spaces.
+/// Strings, characters and comments are kept as is.
+pub(crate) fn format_go_template_action(action: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(action.len());
+    let mut chars = action.trim().chars().peekable();
+    let mut pending_space = false;
+    while let Some(c) = chars.next() {
+        if c.is_ascii_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if c == '|' {
+            result.push_str(" | ");
+            pending_space = false;
+            while chars.next_if(char::is_ascii_whitespace).is_some() {}
+            continue;
+        }
+        if pending_space {
+            result.push(' ');
+            pending_space = false;
+        }
+        result.push(c);
+        match c {
+            '"' | '\'' => {
+                while let Some(c2) = chars.next() {
+                    result.push(c2);
+                    if c2 == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            result.push(escaped);
+                        }
+                    } else if c2 == c {
+                        break;
+                    }
+                }
+            }
+            '`' => {
+                for c2 in chars.by_ref() {
+                    result.push(c2);
+                    if c2 == '`' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                result.push('*');
+                let mut prev = ' ';
+                for c2 in chars.by_ref() {
+                    result.push(c2);
+                    if prev == '*' && c2 == '/' {
+                        break;
+                    }
+                    prev = c2;
+                }
+            }
+            _ => {}
+        }
+    }
+    if result == action {
+        Cow::from(action)
+    } else {
+        Cow::from(result)
+    }
+}
+
+/// Normalizes Go template actions in attribute value as actions in text,
+/// so `{{.Name}}` becomes `{{ .Name }}`, while trim markers and comments are kept.
+pub(crate) fn format_go_template_attr_value(value: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(open) = rest.find("{{") {
+        let Some(close) = find_go_template_action_end(&rest[open + 2..]) else {
+            break;
+        };
+        let action = &rest[open + 2..open + 2 + close];
+        result.push_str(&rest[..open + 2]);
+        rest = &rest[open + 2 + close..];
+
+        // trim markers must be separated from the action by spaces,
+        // otherwise they're part of negative numbers
+        let (trim_prev, action) = match action.strip_prefix('-') {
+            Some(action) if action.starts_with(|c: char| c.is_ascii_whitespace()) => (true, action),
+            _ => (false, action),
+        };
+        let (trim_next, action) = match action.strip_suffix('-') {
+            Some(action) if action.ends_with(|c: char| c.is_ascii_whitespace()) => (true, action),
+            _ => (false, action),
+        };
+        if let Some(comment) = go_template_comment(action) {
+            result.push_str(if trim_prev { "- " } else { "" });
+            result.push_str("/*");
+            result.push_str(comment);
+            result.push_str("*/");
+            result.push_str(if trim_next { " -" } else { "" });
+        } else if !action.trim().is_empty() {
+            result.push_str(if trim_prev { "- " } else { " " });
+            result.push_str(&format_go_template_action(action));
+            result.push_str(if trim_next { " -" } else { " " });
+        } else {
+            result.push_str(action);
+        }
+    }
+    if result.is_empty() {
+        Cow::from(value)
+    } else {
+        result.push_str(rest);
+        Cow::from(result)
+    }
+}
+
+/// Finds the position of closing `}}` of Go template action, skipping strings and comments.
+fn find_go_template_action_end(action: &str) -> Option<usize> {
+    let mut chars = action.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '}' if action[i..].starts_with("}}") => return Some(i),
+            '"' | '\'' => {
+                while let Some((_, c2)) = chars.next() {
+                    if c2 == '\\' {
+                        chars.next();
+                    } else if c2 == c {
+                        break;
+                    }
+                }
+            }
+            '`' => {
+                chars.find(|(_, c2)| *c2 == '`');
+            }
+            '/' if chars.next_if(|(_, c2)| *c2 == '*').is_some() => {
+                let mut prev = ' ';
+                for (_, c2) in chars.by_ref() {
+                    if prev == '*' && c2 == '/' {
+                        break;
+                    }
+                    prev = c2;
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Normalizes whitespaces in Glimmer mustache or sub-expression,
+/// so arguments are separated by one space,
+/// while parentheses, hash pairs like `a=1` and block params like `as |item|` are kept tight.
+pub(crate) fn format_glimmer_expr(expr: &str) -> Cow<'_, str> {
+    let mut result = String::with_capacity(expr.len());
+    let mut chars = expr.trim().chars().peekable();
+    let mut pending_space = false;
+    let mut in_block_params = false;
+    while let Some(c) = chars.next() {
+        if c.is_ascii_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            pending_space = false;
+            let tight = matches!(c, ')' | '=')
+                || c == '|' && in_block_params
+                || result.ends_with(['(', '='])
+                || result.ends_with('|') && in_block_params;
+            if !tight {
+                result.push(' ');
+            }
+        }
+        result.push(c);
+        match c {
+            '"' | '\'' => {
+                while let Some(c2) = chars.next() {
+                    result.push(c2);
+                    if c2 == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            result.push(escaped);
+                        }
+                    } else if c2 == c {
+                        break;
+                    }
+                }
+            }
+            '|' => in_block_params = !in_block_params,
+            _ => {}
+        }
+    }
+    if result == expr {
+        Cow::from(expr)
+    } else {
+        Cow::from(result)
+    }
+}
+
+/// Splits normalized Glimmer mustache or sub-expression into top-level parts,
+/// such as path, positional arguments, hash pairs and block params.
+pub(crate) fn split_glimmer_args(expr: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut in_block_params = false;
+    let mut chars = expr.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if let Some(q) = quote {
+            if c == '\\' {
+                chars.next();
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            '|' => in_block_params = !in_block_params,
+            // keep `as |item|` together
+            ' ' if depth == 0
+                && !in_block_params
+                && !(expr[..i].ends_with(" as") || &expr[..i] == "as") =>
+            {
+                parts.push(&expr[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&expr[start..]);
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Find the next `<template>` tag in JavaScript code of `.gjs` or `.gts` file,
+/// ignoring those inside strings, template literals and comments.
+pub(crate) fn find_glimmer_template(code: &str) -> Option<usize> {
+    let mut chars = code.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '<' && code[i..].starts_with("<template>") {
+            return Some(i);
+        }
+        skip_js_literal_or_comment(c, &mut chars);
+    }
+    None
+}
+
+fn skip_js_literal_or_comment(c: char, chars: &mut Peekable<CharIndices>) {
+    match c {
+        '\'' | '"' => {
+            while let Some((_, ch)) = chars.next() {
+                if ch == '\\' {
+                    chars.next();
+                } else if ch == c || ch == '\n' {
+                    break;
+                }
+            }
+        }
+        '`' => {
+            while let Some((_, ch)) = chars.next() {
+                match ch {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '`' => break,
+                    '$' if chars.next_if(|(_, c)| *c == '{').is_some() => {
+                        let mut depth = 0u16;
+                        while let Some((_, ch)) = chars.next() {
+                            match ch {
+                                '{' => depth += 1,
+                                '}' if depth == 0 => break,
+                                '}' => depth -= 1,
+                                _ => skip_js_literal_or_comment(ch, chars),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        '/' if chars.next_if(|(_, c)| *c == '/').is_some() => {
+            while chars.next_if(|(_, c)| *c != '\n').is_some() {}
+        }
+        '/' if chars.next_if(|(_, c)| *c == '*').is_some() => {
+            while let Some((_, ch)) = chars.next() {
+                if ch == '*' && chars.next_if(|(_, c)| *c == '/').is_some() {
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 pub(crate) static UNESCAPING_AC: LazyLock<AhoCorasick> =
     LazyLock::new(|| AhoCorasick::new(["&quot;", "&#x22;", "&#x27;"]).unwrap());
 
@@ -229,14 +842,212 @@ pub(crate) fn kebab2pascal(s: &'_ str) -> Cow<'_, str> {
     }
 }
 
+pub(crate) enum ScriptKind<'s> {
+    /// JavaScript-like code with its file extension.
+    Js(&'s str),
+    Json,
+    Other,
+}
+
+/// Determines the kind of code inside `<script>` by its `type` and `lang` attributes.
+pub(crate) fn script_kind<'s>(attrs: &[Attribute<'s>], language: Language) -> ScriptKind<'s> {
+    let type_attr = attrs.iter().find_map(|attr| match attr {
+        Attribute::Native(native) if native.name.eq_ignore_ascii_case("type") => {
+            native.value.map(|(value, _)| value.to_ascii_lowercase())
+        }
+        _ => None,
+    });
+    match type_attr.as_deref() {
+        Some(
+            "module"
+            | "application/javascript"
+            | "text/javascript"
+            | "application/ecmascript"
+            | "text/ecmascript"
+            | "application/x-javascript"
+            | "application/x-ecmascript"
+            | "text/x-javascript"
+            | "text/x-ecmascript"
+            | "text/jsx"
+            | "text/babel",
+        )
+        | None => {
+            let lang = attrs
+                .iter()
+                .find_map(|attr| match attr {
+                    Attribute::Native(native) if native.name.eq_ignore_ascii_case("lang") => {
+                        native.value.map(|(value, _)| value)
+                    }
+                    _ => None,
+                })
+                .unwrap_or(if matches!(language, Language::Astro) {
+                    "ts"
+                } else {
+                    "js"
+                });
+            if attrs.iter().any(|attr| match attr {
+                Attribute::Native(native) if native.name.eq_ignore_ascii_case("type") => {
+                    native.value.is_some_and(|(value, _)| value == "module")
+                }
+                _ => false,
+            }) {
+                ScriptKind::Js(match lang {
+                    "ts" => "mts",
+                    "js" => "mjs",
+                    lang => lang,
+                })
+            } else {
+                ScriptKind::Js(lang)
+            }
+        }
+        Some(
+            "importmap"
+            | "application/json"
+            | "text/json"
+            | "application/ld+json"
+            | "speculationrules",
+        ) => ScriptKind::Json,
+        Some(..) => ScriptKind::Other,
+    }
+}
+
+/// Determines the language of code inside `<style>` by its `lang` attribute.
+pub(crate) fn style_lang<'s>(attrs: &[Attribute<'s>]) -> &'s str {
+    lang_attr(attrs).unwrap_or("css")
+}
+
+pub(crate) fn lang_attr<'s>(attrs: &[Attribute<'s>]) -> Option<&'s str> {
+    attrs.iter().find_map(|attr| match attr {
+        Attribute::Native(native_attribute)
+            if native_attribute.name.eq_ignore_ascii_case("lang") =>
+        {
+            native_attribute.value.map(|(value, _)| value)
+        }
+        _ => None,
+    })
+}
+
+/// Detect language of template expressions from `lang` attribute of root-level `<script>`.
+fn detect_expr_ext(nodes: &[Node]) -> &'static str {
+    nodes
+        .iter()
+        .find_map(|node| match &node.kind {
+            NodeKind::Element(element) if element.tag_name.eq_ignore_ascii_case("script") => {
+                lang_attr(&element.attrs)
+            }
+            _ => None,
+        })
+        .map_or("js", |lang| match lang {
+            "ts" => "ts",
+            "tsx" => "tsx",
+            "jsx" => "jsx",
+            _ => "js",
+        })
+}
+
+/// Language of template expressions, which is passed to external formatter as extension.
+pub(crate) fn expr_ext(
+    language: Language,
+    nodes: &[Node],
+    options: &LanguageOptions,
+) -> &'static str {
+    match language {
+        Language::Vue => options
+            .vue_expr_lang
+            .map_or_else(|| detect_expr_ext(nodes), ExprLang::ext),
+        Language::Svelte => options
+            .svelte_expr_lang
+            .map_or_else(|| detect_expr_ext(nodes), ExprLang::ext),
+        _ => "tsx",
+    }
+}
+
+/// Check if root-level `<template>` of Vue or Svelte uses language other than HTML, such as Pug.
+pub(crate) fn is_non_html_template(tag_name: &str, attrs: &[Attribute]) -> bool {
+    tag_name.eq_ignore_ascii_case("template")
+        && lang_attr(attrs).is_some_and(|lang| !lang.eq_ignore_ascii_case("html"))
+}
+
+/// Check if a root-level element of Vue SFC is a custom block.
+/// HTML tags and PascalCase components are excluded, so that fragments can still be formatted.
+pub(crate) fn is_vue_custom_block(tag_name: &str) -> bool {
+    !tag_name.contains(|c: char| c.is_ascii_uppercase())
+        && !["template", "script", "style"].contains(&tag_name)
+        && !is_html_tag(tag_name, Language::Vue)
+        && !is_svg_tag(tag_name, Language::Vue)
+}
+
+/// Rank of attribute in the order recommended by Vue style guide.
+/// Returns `None` for attributes that aren't allowed to be moved.
+///
+/// See https://vuejs.org/style-guide/rules-recommended.html#element-attribute-order.
+fn vue_attr_rank(attr: &Attribute) -> Option<u8> {
+    fn rank_by_name(name: &str) -> u8 {
+        match name {
+            "is" => 0,
+            "id" => 4,
+            "ref" | "key" => 5,
+            "slot" | "slot-scope" => 6,
+            _ => 9,
+        }
+    }
+
+    match attr {
+        Attribute::Native(native) => Some(rank_by_name(native.name)),
+        Attribute::VueDirective(directive) => Some(match directive.name {
+            "is" => 0,
+            "for" => 1,
+            "if" | "else-if" | "else" | "show" | "cloak" => 2,
+            "pre" | "once" => 3,
+            "slot" | "#" => 6,
+            "model" => 7,
+            // attributes can't be moved across `v-bind="obj"`, since their order decides which value wins
+            ":" | "bind" | "." => rank_by_name(
+                directive
+                    .arg_and_modifiers
+                    .and_then(|arg| arg.trim_start_matches(':').split('.').next())?,
+            ),
+            "on" | "@" => 10,
+            "html" | "text" => 11,
+            _ => 8,
+        }),
+        _ => None,
+    }
+}
+
+/// Sort attributes by the order recommended by Vue style guide.
+/// Attributes in the same group keep their relative order,
+/// and attributes are never moved across unknown attributes such as template blocks
+/// or `v-bind` without argument.
+pub(crate) fn sort_vue_attrs<'a, 's>(attrs: &'a [Attribute<'s>]) -> Vec<&'a Attribute<'s>> {
+    let mut sorted = attrs.iter().collect::<Vec<_>>();
+    sorted
+        .split_mut(|attr| vue_attr_rank(attr).is_none())
+        .for_each(|chunk| chunk.sort_by_key(|attr| vue_attr_rank(attr)));
+    sorted
+}
+
 pub(crate) fn has_template_interpolation(s: &str, language: Language) -> bool {
     match language {
         Language::Html | Language::Xml => false,
-        Language::Svelte | Language::Astro => s.contains('{'),
+        Language::Svelte | Language::Astro | Language::Mdx => s.contains('{'),
         Language::Vue | Language::Angular => s.contains("{{"),
-        Language::Jinja | Language::Vento | Language::Mustache => {
+        Language::Vento | Language::Mustache | Language::Glimmer => {
             s.contains("{{") || s.contains("{%")
         }
+        Language::Blade => s.contains("{{") || s.contains("{!!"),
+        Language::Razor => s.contains('@'),
+        Language::Jinja
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Custom(..) => language.dialect().is_some_and(|dialect| {
+            s.contains(dialect.interpolation().0) || s.contains(dialect.tag().0)
+        }),
     }
 }
 
@@ -305,6 +1116,298 @@ pub(crate) fn should_be_space_separated(attr_name: &str, tag_name: Option<&str>)
     }
 }
 
+/// Check if attribute name matches the pattern, where `*` matches any characters.
+pub(crate) fn matches_attr_name_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return false;
+    };
+    let Some(mut rest) = name
+        .get(..first.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(first))
+        .map(|_| &name[first.len()..])
+    else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    if parts.peek().is_none() {
+        return rest.is_empty();
+    }
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.len() >= part.len()
+                && rest
+                    .get(rest.len() - part.len()..)
+                    .is_some_and(|suffix| suffix.eq_ignore_ascii_case(part));
+        }
+        let lower_rest = rest.to_ascii_lowercase();
+        let Some(index) = lower_rest.find(&part.to_ascii_lowercase()) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    true
+}
+
+/// Normalize whitespaces in Thymeleaf standard expression,
+/// so arguments are separated by `, ` and parameters like `id=1` have no spaces around `=`,
+/// while text literals like `'...'` and `|...|` are kept as is.
+pub(crate) fn format_thymeleaf_expr(expr: &str) -> String {
+    let mut result = String::with_capacity(expr.len());
+    let mut chars = expr.trim().chars();
+    let mut quote = None;
+    let mut pending_space = false;
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            result.push(c);
+            if c == '\\' {
+                if let Some(next) = chars.next() {
+                    result.push(next);
+                }
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            c if c.is_ascii_whitespace() => pending_space = true,
+            ',' => {
+                result.push(',');
+                pending_space = true;
+            }
+            '}' | ')' | ']' => {
+                pending_space = false;
+                result.push(c);
+            }
+            // parameters of link expressions like `@{/order(id=${o.id})}` are kept tight,
+            // while comparison operators like `==` and `>=` are not
+            '=' if !result.ends_with(['=', '!', '<', '>'])
+                && !chars.as_str().trim_start().starts_with('=') =>
+            {
+                pending_space = false;
+                result.push(c);
+                chars = chars.as_str().trim_start().chars();
+            }
+            _ => {
+                if pending_space && !result.ends_with(['{', '(', '[']) {
+                    result.push(' ');
+                }
+                pending_space = false;
+                if matches!(c, '\'' | '"' | '|') {
+                    quote = Some(c);
+                }
+                result.push(c);
+            }
+        }
+    }
+    result
+}
+
+/// Split text into words for reflowing,
+/// but Thymeleaf inlined expressions like `[[...]]` and `[(...)]` are kept in one word.
+pub(crate) fn split_thymeleaf_text(text: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let close = if rest.starts_with("[[") {
+            Some("]]")
+        } else if rest.starts_with("[(") {
+            Some(")]")
+        } else {
+            None
+        };
+        if let Some((inner, after)) = close.and_then(|close| rest[2..].split_once(close)) {
+            word.push_str(&rest[..2]);
+            word.push_str(&format_thymeleaf_expr(inner));
+            word.push_str(&rest[2 + inner.len()..2 + inner.len() + 2]);
+            rest = after;
+        } else if c.is_ascii_whitespace() {
+            if !word.is_empty() {
+                words.push(mem::take(&mut word));
+            }
+            rest = &rest[1..];
+        } else {
+            word.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// End condition of CommonMark HTML block.
+pub(crate) enum MarkdownHtmlBlockEnd {
+    /// Block ends at the line which contains this string (ASCII case-insensitive).
+    Contains(&'static str),
+    /// Block ends before the next blank line.
+    BlankLine,
+}
+
+/// Check if a Markdown line starts CommonMark HTML block.
+/// The returned flag indicates whether the block contains elements that can be formatted,
+/// since comments, processing instructions and declarations are kept as is.
+pub(crate) fn markdown_html_block_start(
+    line: &str,
+    in_paragraph: bool,
+) -> Option<(bool, MarkdownHtmlBlockEnd)> {
+    let rest = line.strip_prefix('<')?;
+    if rest.starts_with("!--") {
+        return Some((false, MarkdownHtmlBlockEnd::Contains("-->")));
+    }
+    if rest.starts_with('?') {
+        return Some((false, MarkdownHtmlBlockEnd::Contains("?>")));
+    }
+    if rest.starts_with("![CDATA[") {
+        return Some((false, MarkdownHtmlBlockEnd::Contains("]]>")));
+    }
+    if rest
+        .strip_prefix('!')
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+    {
+        return Some((false, MarkdownHtmlBlockEnd::Contains(">")));
+    }
+
+    let is_closing = rest.starts_with('/');
+    let name_start = rest.trim_start_matches('/');
+    let name_len = name_start
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .unwrap_or(name_start.len());
+    let name = &name_start[..name_len];
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let after_name = &name_start[name_len..];
+
+    if !is_closing
+        && (after_name.is_empty()
+            || after_name.starts_with(|c: char| c.is_ascii_whitespace() || c == '>'))
+        && let Some(end) = ["script", "pre", "style", "textarea"]
+            .iter()
+            .zip(["</script>", "</pre>", "</style>", "</textarea>"])
+            .find_map(|(tag, end)| name.eq_ignore_ascii_case(tag).then_some(end))
+    {
+        return Some((true, MarkdownHtmlBlockEnd::Contains(end)));
+    }
+
+    if (after_name.is_empty()
+        || after_name.starts_with(|c: char| c.is_ascii_whitespace() || c == '>')
+        || after_name.starts_with("/>"))
+        && MARKDOWN_BLOCK_TAGS
+            .iter()
+            .any(|tag| tag.eq_ignore_ascii_case(name))
+    {
+        return Some((true, MarkdownHtmlBlockEnd::BlankLine));
+    }
+
+    // other complete tag which occupies the whole line, but it can't interrupt paragraph
+    if in_paragraph {
+        return None;
+    }
+    let after_tag = if is_closing {
+        after_name.trim_start().strip_prefix('>')?
+    } else {
+        let mut chars = after_name.char_indices();
+        let mut quote = None;
+        loop {
+            match (chars.next()?, quote) {
+                ((_, c), Some(q)) if c == q => quote = None,
+                (_, Some(_)) => {}
+                ((_, c @ ('"' | '\'')), None) => quote = Some(c),
+                ((i, '>'), None) => break &after_name[i + 1..],
+                ((_, '<'), None) => return None,
+                _ => {}
+            }
+        }
+    };
+    if after_tag.trim().is_empty() {
+        Some((true, MarkdownHtmlBlockEnd::BlankLine))
+    } else {
+        None
+    }
+}
+
+static MARKDOWN_BLOCK_TAGS: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// Parse opening code fence of Markdown, returning fence marker and info string.
+pub(crate) fn parse_markdown_code_fence(line: &str) -> Option<(&str, &str)> {
+    let fence_char = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let marker_len = line.len() - line.trim_start_matches(fence_char).len();
+    if marker_len < 3 {
+        return None;
+    }
+    let info = line[marker_len..].trim();
+    if fence_char == '`' && info.contains('`') {
+        return None;
+    }
+    Some((&line[..marker_len], info))
+}
+
 pub(crate) fn pos_to_line_col(source: &str, pos: usize) -> (usize, usize) {
     let search = memchr::memchr_iter(b'\n', source.as_bytes()).try_fold(
         (1, 0),
@@ -322,6 +1425,22 @@ pub(crate) fn pos_to_line_col(source: &str, pos: usize) -> (usize, usize) {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn matches_attr_name_pattern() {
+        use super::matches_attr_name_pattern;
+        assert!(matches_attr_name_pattern("hx-vals", "hx-vals"));
+        assert!(matches_attr_name_pattern("hx-vals", "HX-VALS"));
+        assert!(!matches_attr_name_pattern("hx-vals", "hx-vals-x"));
+        assert!(matches_attr_name_pattern("data-*", "data-config"));
+        assert!(matches_attr_name_pattern("*-json", "x-json"));
+        assert!(matches_attr_name_pattern(
+            "data-*-config",
+            "data-chart-config"
+        ));
+        assert!(!matches_attr_name_pattern("data-*-config", "data-config"));
+        assert!(matches_attr_name_pattern("*", "anything"));
+    }
+
     #[test]
     fn pos_to_line_col() {
         let source = "abc\ndef\nghi";
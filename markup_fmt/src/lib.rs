@@ -1,15 +1,26 @@
 #![doc = include_str!("../README.md")]
 
-mod ast;
+pub mod ast;
 pub mod config;
 mod ctx;
+pub mod dialect;
 mod error;
 mod helpers;
 mod parser;
 mod printer;
 mod state;
+pub mod structure;
+pub mod tokens;
+pub mod transform;
 
-use crate::{config::FormatOptions, ctx::Ctx, parser::Parser, printer::DocGen, state::State};
+use crate::{
+    config::FormatOptions,
+    ctx::Ctx,
+    parser::Parser,
+    printer::DocGen,
+    state::State,
+    transform::{Strings, Transformer},
+};
 pub use crate::{ctx::Hints, error::*, parser::Language};
 use anyhow::Error;
 use std::{borrow::Cow, path::Path};
@@ -56,8 +67,49 @@ pub fn format_text<F>(
 where
     F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
 {
+    format_text_with_transform(code, language, options, |_, _| {}, external_formatter)
+}
+
+/// Format the given source code with transforming AST before printing.
+///
+/// The transform hook receives parsed AST and a [`Transformer`]
+/// which helps to create AST parts from owned strings.
+///
+/// ```
+/// use markup_fmt::{format_text_with_transform, transform::visit_elements_mut, Language};
+///
+/// let formatted = format_text_with_transform(
+///     "<old-button data-testid=\"submit\">Submit</old-button>",
+///     Language::Html,
+///     &Default::default(),
+///     |root, transformer| {
+///         visit_elements_mut(&mut root.children, &mut |element| {
+///             if element.tag_name == "old-button" {
+///                 transformer.rename_element(element, "new-button");
+///             }
+///             element.attrs.retain(|attr| {
+///                 !matches!(attr, markup_fmt::ast::Attribute::Native(native) if native.name == "data-testid")
+///             });
+///         });
+///     },
+///     |code, _| Ok(code.into()),
+/// ).unwrap();
+/// assert_eq!(formatted, "<new-button>Submit</new-button>\n");
+/// ```
+pub fn format_text_with_transform<F, T>(
+    code: &str,
+    language: Language,
+    options: &FormatOptions,
+    transform: T,
+    external_formatter: F,
+) -> Result<String, FormatError>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    T: for<'s> FnOnce(&mut ast::Root<'s>, &Transformer<'s>),
+{
+    let strings = Strings::default();
     let mut parser = Parser::new(code, language);
-    let ast = parser.parse_root().map_err(FormatError::Syntax)?;
+    let mut ast = parser.parse_root().map_err(FormatError::Syntax)?;
 
     if ast.children.first().is_some_and(|child| {
         if let ast::Node {
@@ -77,12 +129,15 @@ where
         return Ok(code.into());
     }
 
+    transform(&mut ast, &Transformer::new(&strings, language));
+
     let mut ctx = Ctx {
         source: code,
         language,
         indent_width: options.layout.indent_width,
         print_width: options.layout.print_width,
         options: &options.language,
+        expr_ext: helpers::expr_ext(language, &ast.children, &options.language),
         external_formatter,
         external_formatter_errors: Default::default(),
     };
@@ -115,7 +170,392 @@ where
     ))
 }
 
+/// Format HTML inside tagged template literal, such as `` html`...` `` of Lit.
+///
+/// The template literal is given as its static chunks,
+/// and each `${...}` hole is between two adjacent chunks,
+/// just like `strings` argument of a tag function.
+/// Holes can be placed in text, attribute values or attribute name positions,
+/// for example `@click=${handler}`, `.value=${value}` and `?disabled=${disabled}`.
+///
+/// It returns formatted static chunks with the same count,
+/// so JavaScript formatters can join them with formatted expressions.
+/// If holes can't be kept in formatted code, original chunks are returned.
+///
+/// ```
+/// use markup_fmt::format_template_literal;
+///
+/// let formatted = format_template_literal(
+///     &["<button   @click=", " ?disabled=", ">", "</button>"],
+///     &Default::default(),
+///     |code, _| Ok(code.into()),
+/// ).unwrap();
+/// assert_eq!(
+///     formatted,
+///     ["<button @click=", " ?disabled=", ">", "</button>"],
+/// );
+/// ```
+pub fn format_template_literal<F>(
+    statics: &[&str],
+    options: &FormatOptions,
+    external_formatter: F,
+) -> Result<Vec<String>, FormatError>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    let mut prefix = String::from("__h");
+    while statics.iter().any(|s| s.contains(&prefix)) {
+        prefix.push('_');
+    }
+    let placeholder = |i: usize| format!("{prefix}{i}_");
+
+    let mut code = String::with_capacity(statics.iter().map(|s| s.len() + prefix.len() + 4).sum());
+    for (i, s) in statics.iter().enumerate() {
+        if i > 0 {
+            code.push_str(&placeholder(i - 1));
+        }
+        code.push_str(s);
+    }
+    let formatted = format_text(&code, Language::Html, options, external_formatter)?;
+    // trailing line break isn't part of the template literal
+    let formatted = formatted.strip_suffix('\n').unwrap_or(&formatted);
+    let formatted = formatted.strip_suffix('\r').unwrap_or(formatted);
+
+    let mut result = Vec::with_capacity(statics.len());
+    let mut rest = formatted;
+    for i in 0..statics.len().saturating_sub(1) {
+        let placeholder = placeholder(i);
+        let Some((mut before, after)) = rest.split_once(&placeholder) else {
+            return Ok(statics.iter().map(|s| s.to_string()).collect());
+        };
+        rest = after;
+        // unquoted attribute value is quoted by formatter, but it should be kept as is
+        if statics[i].ends_with('=')
+            && statics[i + 1].starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            && let Some(quote) = before
+                .chars()
+                .next_back()
+                .filter(|c| matches!(c, '"' | '\''))
+            && rest.starts_with(quote)
+        {
+            before = &before[..before.len() - 1];
+            rest = &rest[1..];
+        }
+        result.push(before.to_owned());
+    }
+    result.push(rest.to_owned());
+    Ok(result)
+}
+
+/// Format Glimmer templates inside `.gjs` or `.gts` file.
+///
+/// Each `<template>` tag is formatted as [`Language::Glimmer`] and
+/// indented according to the line where it starts,
+/// while JavaScript or TypeScript code outside is left as is.
+/// `<template>` inside strings, template literals or comments isn't treated as tag.
+///
+/// ```
+/// use markup_fmt::format_glimmer_script;
+///
+/// let formatted = format_glimmer_script(
+///     "export default class extends Component {\n  <template><p>{{this.name}}</p></template>\n}\n",
+///     &Default::default(),
+///     |code, _| Ok(code.into()),
+/// ).unwrap();
+/// assert_eq!(
+///     formatted,
+///     "export default class extends Component {\n  <template>\n    <p>{{this.name}}</p>\n  </template>\n}\n",
+/// );
+/// ```
+pub fn format_glimmer_script<F>(
+    code: &str,
+    options: &FormatOptions,
+    mut external_formatter: F,
+) -> Result<String, FormatError>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    const OPEN: &str = "<template>";
+    const CLOSE: &str = "</template>";
+
+    let line_break = match options.layout.line_break {
+        config::LineBreak::Lf => "\n",
+        config::LineBreak::Crlf => "\r\n",
+    };
+    let indent_unit = if options.layout.use_tabs {
+        "\t".to_owned()
+    } else {
+        " ".repeat(options.layout.indent_width)
+    };
+
+    let mut result = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(open) = helpers::find_glimmer_template(rest) {
+        let content_start = open + OPEN.len();
+        // templates can contain nested `<template>` elements
+        let mut depth = 1usize;
+        let mut pos = content_start;
+        let content_end = loop {
+            let next_open = rest[pos..].find("<template").map(|i| pos + i);
+            let Some(next_close) = rest[pos..].find(CLOSE).map(|i| pos + i) else {
+                break None;
+            };
+            match next_open {
+                Some(next_open) if next_open < next_close => {
+                    depth += 1;
+                    pos = next_open + 1;
+                }
+                _ => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break Some(next_close);
+                    }
+                    pos = next_close + CLOSE.len();
+                }
+            }
+        };
+        let Some(content_end) = content_end else {
+            break;
+        };
+
+        let before = &rest[..open];
+        let line_start = result.len() + before.rfind('\n').map_or(0, |i| i + 1);
+        result.push_str(before);
+        let base_indent = result[line_start..]
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect::<String>();
+        let inner_indent = format!("{base_indent}{indent_unit}");
+
+        let mut template_options = options.clone();
+        template_options.layout.print_width = options
+            .layout
+            .print_width
+            .saturating_sub(base_indent.len() + indent_unit.len());
+        let formatted = format_text(
+            &rest[content_start..content_end],
+            Language::Glimmer,
+            &template_options,
+            &mut external_formatter,
+        )?;
+
+        result.push_str(OPEN);
+        if !formatted.trim().is_empty() {
+            for line in formatted.lines() {
+                result.push_str(line_break);
+                if !line.is_empty() {
+                    result.push_str(&inner_indent);
+                    result.push_str(line);
+                }
+            }
+            result.push_str(line_break);
+            result.push_str(&base_indent);
+        }
+        result.push_str(CLOSE);
+        rest = &rest[content_end + CLOSE.len()..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Format HTML inside Markdown file.
+///
+/// CommonMark HTML blocks are formatted as [`Language::Html`],
+/// and fenced code blocks whose info string is `html`, `vue` or `svelte`
+/// are formatted as corresponding language, keeping their indentation.
+/// HTML comments, blocks that can't be parsed on their own
+/// (such as `<details>` wrapping Markdown content)
+/// and all other Markdown content are left as is.
+///
+/// ```
+/// use markup_fmt::format_markdown_html;
+///
+/// let formatted = format_markdown_html(
+///     "# Title\n\n<div   align=\"center\"><img src=\"logo.png\"></div>\n\n*text*\n",
+///     &Default::default(),
+///     |code, _| Ok(code.into()),
+/// ).unwrap();
+/// assert_eq!(
+///     formatted,
+///     "# Title\n\n<div align=\"center\"><img src=\"logo.png\"></div>\n\n*text*\n",
+/// );
+/// ```
+pub fn format_markdown_html<F>(
+    code: &str,
+    options: &FormatOptions,
+    mut external_formatter: F,
+) -> Result<String, FormatError>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    let line_break = match options.layout.line_break {
+        config::LineBreak::Lf => "\n",
+        config::LineBreak::Crlf => "\r\n",
+    };
+    let mut format_block = |lines: &[&str],
+                            indent: &str,
+                            language: Language,
+                            allow_empty_lines: bool|
+     -> Result<Option<String>, FormatError> {
+        let mut code = String::new();
+        for line in lines {
+            let line = line.trim_end();
+            if !line.is_empty() {
+                let Some(line) = line.strip_prefix(indent) else {
+                    return Ok(None);
+                };
+                code.push_str(line);
+            }
+            code.push('\n');
+        }
+
+        let mut block_options = options.clone();
+        block_options.layout.print_width = options.layout.print_width.saturating_sub(indent.len());
+        let formatted = match format_text(&code, language, &block_options, &mut external_formatter)
+        {
+            Ok(formatted) => formatted,
+            Err(FormatError::Syntax(..)) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let formatted = formatted.trim_end();
+        // HTML blocks end at blank line, so adding blank lines would change the document
+        if !allow_empty_lines && formatted.lines().any(|line| line.trim().is_empty()) {
+            return Ok(None);
+        }
+
+        let mut result = String::with_capacity(formatted.len());
+        for line in formatted.lines().filter(|_| !formatted.is_empty()) {
+            if !line.is_empty() {
+                result.push_str(indent);
+                result.push_str(line);
+            }
+            result.push_str(line_break);
+        }
+        if lines.last().is_some_and(|line| !line.ends_with('\n')) {
+            result.truncate(result.len() - line_break.len());
+        }
+        Ok(Some(result))
+    };
+
+    let lines = code.split_inclusive('\n').collect::<Vec<_>>();
+    let mut result = String::with_capacity(code.len());
+    let mut i = 0;
+    if lines.first().is_some_and(|line| line.trim_end() == "---")
+        && let Some(end) = lines
+            .iter()
+            .skip(1)
+            .position(|line| matches!(line.trim_end(), "---" | "..."))
+    {
+        // front matter
+        i = end + 2;
+        lines[..i].iter().for_each(|line| result.push_str(line));
+    }
+
+    let mut in_paragraph = false;
+    while let Some(line) = lines.get(i) {
+        let content = line.trim_end();
+        let trimmed = content.trim_start();
+        let indent = &content[..content.len() - trimmed.len()];
+        if trimmed.is_empty() {
+            in_paragraph = false;
+            result.push_str(line);
+            i += 1;
+            continue;
+        }
+
+        if let Some((marker, info)) = helpers::parse_markdown_code_fence(trimmed) {
+            let fence_char = &marker[..1];
+            let Some(end) = lines.iter().skip(i + 1).position(|line| {
+                let line = line.trim();
+                line.starts_with(marker) && line.trim_start_matches(fence_char).is_empty()
+            }) else {
+                // unclosed code fence lasts until the end of document
+                lines[i..].iter().for_each(|line| result.push_str(line));
+                break;
+            };
+            let end = i + 1 + end;
+            let language = match info.split_ascii_whitespace().next() {
+                Some("html" | "htm") => Some(Language::Html),
+                Some("vue") => Some(Language::Vue),
+                Some("svelte") => Some(Language::Svelte),
+                _ => None,
+            };
+            let formatted = if let Some(language) = language {
+                format_block(&lines[i + 1..end], indent, language, true)?
+            } else {
+                None
+            };
+            if let Some(formatted) = formatted {
+                result.push_str(line);
+                result.push_str(&formatted);
+                result.push_str(lines[end]);
+            } else {
+                lines[i..=end].iter().for_each(|line| result.push_str(line));
+            }
+            in_paragraph = false;
+            i = end + 1;
+            continue;
+        }
+
+        if indent.len() < 4
+            && let Some((can_format, block_end)) =
+                helpers::markdown_html_block_start(trimmed, in_paragraph)
+        {
+            let end = match block_end {
+                helpers::MarkdownHtmlBlockEnd::Contains(close) => lines
+                    .iter()
+                    .skip(i)
+                    .position(|line| line.to_ascii_lowercase().contains(close))
+                    .map_or(lines.len(), |offset| i + offset + 1),
+                helpers::MarkdownHtmlBlockEnd::BlankLine => lines
+                    .iter()
+                    .skip(i)
+                    .position(|line| line.trim().is_empty())
+                    .map_or(lines.len(), |offset| i + offset),
+            };
+            let formatted = if can_format {
+                format_block(
+                    &lines[i..end],
+                    indent,
+                    Language::Html,
+                    matches!(block_end, helpers::MarkdownHtmlBlockEnd::Contains(..)),
+                )?
+            } else {
+                None
+            };
+            if let Some(formatted) = formatted {
+                result.push_str(&formatted);
+            } else {
+                lines[i..end].iter().for_each(|line| result.push_str(line));
+            }
+            in_paragraph = false;
+            i = end;
+            continue;
+        }
+
+        in_paragraph = !trimmed.starts_with('#');
+        result.push_str(line);
+        i += 1;
+    }
+    Ok(result)
+}
+
+/// Parse the given source code into AST without formatting it.
+///
+/// ```
+/// use markup_fmt::{ast::NodeKind, parse, Language};
+///
+/// let root = parse("<div></div>", Language::Html).unwrap();
+/// assert!(matches!(root.children[0].kind, NodeKind::Element(..)));
+/// ```
+pub fn parse(code: &str, language: Language) -> Result<ast::Root<'_>, SyntaxError> {
+    Parser::new(code, language).parse_root()
+}
+
 /// Detect language from file extension.
+///
+/// `.hbs` files are detected as [`Language::Mustache`];
+/// use [`Language::Glimmer`] explicitly for Ember templates.
 pub fn detect_language(path: impl AsRef<Path>) -> Option<Language> {
     let path = path.as_ref();
     match path.extension().and_then(std::ffi::OsStr::to_str) {
@@ -132,8 +572,24 @@ pub fn detect_language(path: impl AsRef<Path>) -> Option<Language> {
         Some("vue") => Some(Language::Vue),
         Some("svelte") => Some(Language::Svelte),
         Some("astro") => Some(Language::Astro),
+        Some("mdx") => Some(Language::Mdx),
         Some("jinja" | "jinja2" | "j2" | "twig" | "njk") => Some(Language::Jinja),
         Some("vto") => Some(Language::Vento),
+        Some("liquid") => Some(Language::Liquid),
+        Some("django" | "djhtml") => Some(Language::Django),
+        Some("gohtml" | "gotmpl" | "tmpl") => Some(Language::GoTemplate),
+        Some("erb") => Some(Language::Erb),
+        Some("ejs") => Some(Language::Ejs),
+        Some("heex") => Some(Language::Heex),
+        Some("php")
+            if path
+                .file_stem()
+                .is_some_and(|file_stem| file_stem.to_string_lossy().ends_with(".blade")) =>
+        {
+            Some(Language::Blade)
+        }
+        Some("php" | "phtml") => Some(Language::Php),
+        Some("cshtml" | "razor") => Some(Language::Razor),
         Some("mustache" | "hbs" | "handlebars") => Some(Language::Mustache),
         Some("xml" | "svg" | "wsdl" | "xsd" | "xslt" | "xsl") => Some(Language::Xml),
         _ => None,
@@ -203,4 +659,380 @@ mod tests {
         );
         assert_eq!(ext.as_deref(), Some("tsx"));
     }
+
+    #[test]
+    fn vue_custom_block_ext() {
+        let mut exts = vec![];
+        let _ = format_text(
+            "<i18n lang=\"yaml\">a: 1</i18n>\n<docs># Title</docs>\n<unknown>text</unknown>",
+            Language::Vue,
+            &Default::default(),
+            |code, hints| {
+                exts.push(hints.ext.to_owned());
+                Ok(Cow::from(code))
+            },
+        );
+        assert_eq!(exts, ["yaml", "md"]);
+    }
+
+    #[test]
+    fn vue_custom_block_error() {
+        let result = format_text(
+            "<i18n lang=\"yaml\">a: [</i18n>\n",
+            Language::Vue,
+            &Default::default(),
+            |code, hints| {
+                if hints.ext == "yaml" {
+                    Err(anyhow::anyhow!("invalid YAML"))
+                } else {
+                    Ok(Cow::from(code))
+                }
+            },
+        );
+        assert!(matches!(result, Err(FormatError::External(errors)) if errors.len() == 1));
+    }
+
+    #[test]
+    fn vue_expr_ext() {
+        let detect = |code: &str, options: &FormatOptions| {
+            let mut exts = vec![];
+            let _ = format_text(code, Language::Vue, options, |code, hints| {
+                exts.push(hints.ext.to_owned());
+                Ok(Cow::from(code))
+            });
+            exts.first().cloned()
+        };
+        let code = "<template><p>{{ a }}</p></template>";
+        assert_eq!(detect(code, &Default::default()).as_deref(), Some("js"));
+        let code = "<template><p>{{ a }}</p></template><script setup lang=\"ts\"></script>";
+        assert_eq!(detect(code, &Default::default()).as_deref(), Some("ts"));
+
+        let mut options = FormatOptions::default();
+        options.language.vue_expr_lang = Some(config::ExprLang::Tsx);
+        assert_eq!(detect(code, &options).as_deref(), Some("tsx"));
+    }
+
+    #[test]
+    fn svelte_expr_ext() {
+        let detect = |code: &str, options: &FormatOptions| {
+            let mut exts = vec![];
+            let _ = format_text(code, Language::Svelte, options, |code, hints| {
+                exts.push(hints.ext.to_owned());
+                Ok(Cow::from(code))
+            });
+            exts.last().cloned()
+        };
+        let code = "<p>{a}</p>";
+        assert_eq!(detect(code, &Default::default()).as_deref(), Some("js"));
+        let code = "<script lang=\"ts\"></script><p>{a}</p>";
+        assert_eq!(detect(code, &Default::default()).as_deref(), Some("ts"));
+
+        let mut options = FormatOptions::default();
+        options.language.svelte_expr_lang = Some(config::ExprLang::Jsx);
+        assert_eq!(detect(code, &options).as_deref(), Some("jsx"));
+    }
+
+    #[test]
+    fn erb_ejs_code() {
+        let format = |code: &str, language| {
+            let mut codes = vec![];
+            let formatted = format_text(code, language, &Default::default(), |code, hints| {
+                codes.push(format!("{}: {code}", hints.ext));
+                // JavaScript formatters add semicolons
+                Ok(Cow::from(if hints.ext == "js" {
+                    format!("{};", code.trim())
+                } else {
+                    code.to_owned()
+                }))
+            })
+            .unwrap();
+            (formatted, codes)
+        };
+
+        let (formatted, codes) = format(
+            "<% items.forEach((item) => { %><%- item %><% }) %>\n<% if (a) { %><% let b = 1 %><% } else { %>b<% } %>",
+            Language::Ejs,
+        );
+        assert_eq!(
+            formatted,
+            "<% items.forEach((item) => { %><%- item %><% }) %>\n<% if (a) { %><% let b = 1; %><% } else { %>b<% } %>\n"
+        );
+        assert_eq!(
+            codes,
+            [
+                "js: items.forEach((item) => {\n})",
+                "js:  item ",
+                "js: if (a) {\n}",
+                "js:  let b = 1 ",
+                "js: if (_) {\n} else {\n}",
+            ]
+        );
+
+        let (formatted, codes) = format(
+            "<% items.each do |item| %><%= item %><% end %>\n<% if a %>a<% elsif b %>b<% end %>",
+            Language::Erb,
+        );
+        assert_eq!(
+            formatted,
+            "<% items.each do |item| %><%= item %><% end %>\n<% if a %>a<% elsif b %>b<% end %>\n"
+        );
+        assert_eq!(
+            codes,
+            [
+                "rb: items.each do |item|\nend",
+                "rb:  item ",
+                "rb: if a\nend",
+                "rb: if _\nelsif b\nend",
+            ]
+        );
+    }
+
+    #[test]
+    fn razor_code_block_with_markup() {
+        let code = "@{\n    if (a) { <p>x</p> }\n}\n@{\n    var b = 1;\n}\n";
+        let mut codes = vec![];
+        let formatted = format_text(code, Language::Razor, &Default::default(), |code, hints| {
+            assert_eq!(hints.ext, "cs");
+            codes.push(code.to_owned());
+            Ok(code.into())
+        })
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "@{\n    if (a) { <p>x</p> }\n}\n@{\n  var b = 1;\n}\n"
+        );
+        assert_eq!(codes, ["\n    var b = 1;\n"]);
+    }
+
+    #[test]
+    fn template_lang_ext() {
+        let mut ext = None;
+        let _ = format_text(
+            "<template lang=\"pug\">\ndiv\n  p text\n</template>\n",
+            Language::Svelte,
+            &Default::default(),
+            |code, hints| {
+                ext = Some(hints.ext.to_owned());
+                Ok(Cow::from(code))
+            },
+        );
+        assert_eq!(ext.as_deref(), Some("pug"));
+    }
+
+    #[test]
+    fn vue_custom_block_indent() {
+        let formatted = format_text(
+            "<i18n lang=\"yaml\">\nen:\n    hello:   Hello\n</i18n>\n",
+            Language::Vue,
+            &Default::default(),
+            |code, hints| {
+                if hints.ext == "yaml" {
+                    Ok(Cow::from("en:\n  hello: Hello\n".to_owned()))
+                } else {
+                    Ok(Cow::from(code))
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "<i18n lang=\"yaml\">\nen:\n  hello: Hello\n</i18n>\n"
+        );
+    }
+
+    #[test]
+    fn template_literal_holes() {
+        let formatted = format_template_literal(
+            &[
+                "<ul class=\"",
+                "\">\n",
+                "<li .item=",
+                " title='",
+                "'>",
+                "</li></ul>",
+            ],
+            &Default::default(),
+            |code, _| Ok(Cow::from(code)),
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            [
+                "<ul class=\"",
+                "\">\n  ",
+                "<li .item=",
+                " title=\"",
+                "\">",
+                "</li>\n</ul>",
+            ],
+        );
+    }
+
+    #[test]
+    fn template_literal_holes_at_edges() {
+        let format = |statics: &[&str]| {
+            format_template_literal(statics, &Default::default(), |code, _| Ok(Cow::from(code)))
+                .unwrap()
+        };
+        assert_eq!(format(&["", "<p>  text</p>"]), ["", "<p>text</p>"]);
+        assert_eq!(format(&["<p>text  </p>", ""]), ["<p>text</p>", ""]);
+        assert_eq!(format(&["", "<b>x</b>", ""]), ["", "<b>x</b>", ""]);
+    }
+
+    #[test]
+    fn template_literal_holes_in_attrs() {
+        let formatted = format_template_literal(
+            &["<a   href=\"", "\" class=", "   ?hidden=", ">link</a>"],
+            &Default::default(),
+            |code, _| Ok(Cow::from(code)),
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            ["<a href=\"", "\" class=", " ?hidden=", ">link</a>"],
+        );
+    }
+
+    #[test]
+    fn template_literal_no_trailing_line_break() {
+        let formatted =
+            format_template_literal(&["<button>Go</button>"], &Default::default(), |code, _| {
+                Ok(Cow::from(code))
+            })
+            .unwrap();
+        assert_eq!(formatted, ["<button>Go</button>"]);
+
+        let mut options = FormatOptions::default();
+        options.layout.line_break = config::LineBreak::Crlf;
+        let formatted =
+            format_template_literal(&["<div>\n<p>a</p>\n</div>"], &options, |code, _| {
+                Ok(Cow::from(code))
+            })
+            .unwrap();
+        assert_eq!(formatted, ["<div>\r\n  <p>a</p>\r\n</div>"]);
+    }
+
+    #[test]
+    fn transform_replace_nodes() {
+        let formatted = format_text_with_transform(
+            "<main><legacy-banner /><p>text</p></main>",
+            Language::Vue,
+            &Default::default(),
+            |root, transformer| {
+                transform::visit_children_mut(&mut root.children, &mut |nodes| {
+                    if let Some(index) = nodes.iter().position(|node| {
+                        matches!(&node.kind, ast::NodeKind::Element(element) if element.tag_name == "legacy-banner")
+                    }) {
+                        let replacement = transformer
+                            .parse_nodes("<Banner :variant=\"'legacy'\" />")
+                            .unwrap();
+                        nodes.splice(index..=index, replacement);
+                    }
+                });
+            },
+            |code, _| Ok(code.into()),
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "<main><Banner :variant=\"'legacy'\" /><p>text</p></main>\n"
+        );
+    }
+
+    #[test]
+    fn transform_blade_block() {
+        let formatted = format_text_with_transform(
+            "@if ($user)\n<user-card />\n@endif\n",
+            Language::Blade,
+            &Default::default(),
+            |root, transformer| {
+                transform::visit_elements_mut(&mut root.children, &mut |element| {
+                    transformer.rename_element(element, "profile-card");
+                });
+            },
+            |code, _| Ok(code.into()),
+        )
+        .unwrap();
+        assert_eq!(formatted, "@if ($user)\n  <profile-card />\n@endif\n");
+    }
+
+    #[test]
+    fn blade_component_attr_as_php() {
+        let mut codes = vec![];
+        let formatted = format_text(
+            "<x-alert :items=\"[ 'a' ]\" title=\"[ 'b' ]\" />",
+            Language::Blade,
+            &Default::default(),
+            |code, hints| {
+                codes.push((code.to_owned(), hints.ext.to_owned()));
+                Ok(Cow::from(code.replace("[ ", "[").replace(" ]", "]")))
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "<x-alert :items=\"['a']\" title=\"[ 'b' ]\" />\n"
+        );
+        assert_eq!(codes, [("<?php _([ 'a' ]);".to_owned(), "php".to_owned())]);
+    }
+
+    #[test]
+    fn transform_razor_block() {
+        let formatted = format_text_with_transform(
+            "@if (user != null) {\n<user-card />\n}\n",
+            Language::Razor,
+            &Default::default(),
+            |root, transformer| {
+                transform::visit_elements_mut(&mut root.children, &mut |element| {
+                    transformer.rename_element(element, "profile-card");
+                });
+            },
+            |code, _| Ok(code.into()),
+        )
+        .unwrap();
+        assert_eq!(formatted, "@if (user != null) {\n  <profile-card />\n}\n");
+    }
+
+    #[test]
+    fn php_block_start_multibyte() {
+        use dialect::Dialect;
+
+        assert!(dialect::Php.is_block_start("if", "if ($a):"));
+        assert!(!dialect::Php.is_block_start("if", "中 ($a):"));
+    }
+
+    #[test]
+    fn attr_offsets() {
+        let code = r#"<div id="a" v-if="b" :c="d"></div>"#;
+        let root = parse(code, Language::Vue).unwrap();
+        let ast::NodeKind::Element(element) = &root.children[0].kind else {
+            panic!("expected element");
+        };
+        let offsets = element
+            .attrs
+            .iter()
+            .map(|attr| match attr {
+                ast::Attribute::Native(native) => native.start,
+                ast::Attribute::VueDirective(directive) => directive.start,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [5, 12, 21]);
+
+        let code = "<Comp a={b} {c} {@attach d} />";
+        let root = parse(code, Language::Svelte).unwrap();
+        let ast::NodeKind::Element(element) = &root.children[0].kind else {
+            panic!("expected element");
+        };
+        let offsets = element
+            .attrs
+            .iter()
+            .map(|attr| match attr {
+                ast::Attribute::Svelte(svelte) => svelte.start,
+                ast::Attribute::SvelteAttachment(attachment) => attachment.start,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [6, 12, 16]);
+    }
 }
@@ -9,10 +9,11 @@
 
 use crate::{
     ast::*,
+    dialect::{self, Dialect, DialectDelimiter},
     error::{SyntaxError, SyntaxErrorKind},
     helpers,
 };
-use std::{iter::Peekable, str::CharIndices};
+use std::{iter::Peekable, mem, str::CharIndices};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// Supported languages.
@@ -26,6 +27,37 @@ pub enum Language {
     Vento,
     Mustache,
     Xml,
+    Liquid,
+    Django,
+    GoTemplate,
+    Erb,
+    Ejs,
+    Heex,
+    Blade,
+    Razor,
+    Glimmer,
+    Php,
+    Mdx,
+    /// Jinja-like template language with custom syntax.
+    Custom(&'static dyn Dialect),
+}
+
+impl Language {
+    /// Returns the dialect if this is a Jinja-like template language.
+    pub(crate) fn dialect(self) -> Option<&'static dyn Dialect> {
+        match self {
+            Language::Jinja => Some(&dialect::Jinja),
+            Language::Liquid => Some(&dialect::Liquid),
+            Language::Django => Some(&dialect::Django),
+            Language::GoTemplate => Some(&dialect::GoTemplate),
+            Language::Erb => Some(&dialect::Erb),
+            Language::Ejs => Some(&dialect::Ejs),
+            Language::Heex => Some(&dialect::Heex),
+            Language::Php => Some(&dialect::Php),
+            Language::Custom(dialect) => Some(dialect),
+            _ => None,
+        }
+    }
 }
 
 pub struct Parser<'s> {
@@ -35,9 +67,13 @@ pub struct Parser<'s> {
     state: ParserState,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct ParserState {
     has_front_matter: bool,
+    /// Count of elements which we're inside.
+    element_depth: usize,
+    /// Whether we're directly inside Razor code block, where each line of text is a C# statement.
+    razor_code_context: bool,
 }
 
 impl<'s> Parser<'s> {
@@ -55,9 +91,11 @@ impl<'s> Parser<'s> {
         F: FnOnce(&mut Self) -> PResult<R>,
     {
         let chars = self.chars.clone();
+        let state = self.state.clone();
         let result = f(self);
         if result.is_err() {
             self.chars = chars;
+            self.state = state;
         }
         result
     }
@@ -130,14 +168,33 @@ impl<'s> Parser<'s> {
         last
     }
 
-    fn with_taken<T, F>(&mut self, parser: F) -> PResult<(T, &'s str)>
+    /// Checks if the rest of source starts with the given string without consuming it.
+    fn peek_str(&mut self, s: &str) -> bool {
+        let pos = self.peek_pos();
+        self.source
+            .get(pos..)
+            .is_some_and(|rest| rest.starts_with(s))
+    }
+
+    fn peek_dialect_delimiter(&mut self, dialect: &dyn Dialect) -> Option<DialectDelimiter> {
+        let pos = self.peek_pos();
+        self.source
+            .get(pos..)
+            .and_then(|rest| dialect::match_opening_delimiter(dialect, rest))
+    }
+
+    fn with_taken<T, F>(&mut self, parser: F) -> PResult<(T, &'s str, usize)>
     where
         F: FnOnce(&mut Self) -> PResult<T>,
     {
         let start = self.peek_pos();
         let parsed = parser(self)?;
         let end = self.peek_pos();
-        Ok((parsed, unsafe { self.source.get_unchecked(start..end) }))
+        Ok((
+            parsed,
+            unsafe { self.source.get_unchecked(start..end) },
+            start,
+        ))
     }
 
     fn parse_angular_control_flow_children(&mut self) -> PResult<Vec<Node<'s>>> {
@@ -526,6 +583,7 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_astro_attr(&mut self) -> PResult<AstroAttribute<'s>> {
+        let attr_start = self.peek_pos();
         let (name, start, first_char) =
             if let Some((start, first_char)) = self.chars.next_if(|(_, c)| *c == '{') {
                 (None, start, first_char)
@@ -550,13 +608,18 @@ impl<'s> Parser<'s> {
                 Ok(AstroAttribute {
                     name,
                     expr: unsafe { (self.source.get_unchecked(start..end + 1), start) },
+                    start: attr_start,
                 })
             } else {
                 Err(self.emit_error(SyntaxErrorKind::ExpectAstroAttr))
             }
         } else {
             self.parse_svelte_or_astro_expr()
-                .map(|expr| AstroAttribute { name, expr })
+                .map(|expr| AstroAttribute {
+                    name,
+                    expr,
+                    start: attr_start,
+                })
         }
     }
 
@@ -610,6 +673,7 @@ impl<'s> Parser<'s> {
                                         start: pos,
                                     }),
                                     raw: prev,
+                                    start: pos,
                                 });
                             }
                         } else {
@@ -695,9 +759,30 @@ impl<'s> Parser<'s> {
 
     fn parse_attr(&mut self) -> PResult<Attribute<'s>> {
         match self.language {
-            Language::Html | Language::Angular | Language::Mustache | Language::Xml => {
-                self.parse_native_attr().map(Attribute::Native)
-            }
+            Language::Html
+            | Language::Angular
+            | Language::Mustache
+            | Language::Glimmer
+            | Language::Xml
+            | Language::Razor => self.parse_native_attr().map(Attribute::Native),
+            Language::Blade => match self.chars.peek() {
+                Some((_, '@')) => self
+                    .try_parse(|parser| {
+                        let directive = parser.parse_blade_directive()?;
+                        if directive.args.is_some() {
+                            Ok(directive)
+                        } else {
+                            Err(parser.emit_error(SyntaxErrorKind::ExpectBladeDirective))
+                        }
+                    })
+                    .map(Attribute::BladeDirective)
+                    .or_else(|_| self.parse_native_attr().map(Attribute::Native)),
+                Some((_, '{')) => self
+                    .try_parse(Parser::parse_blade_echo)
+                    .map(Attribute::BladeEcho)
+                    .or_else(|_| self.parse_native_attr().map(Attribute::Native)),
+                _ => self.parse_native_attr().map(Attribute::Native),
+            },
             Language::Vue => self
                 .try_parse(Parser::parse_vue_directive)
                 .map(Attribute::VueDirective)
@@ -710,22 +795,41 @@ impl<'s> Parser<'s> {
                         .map(Attribute::Svelte)
                 })
                 .or_else(|_| self.parse_native_attr().map(Attribute::Native)),
-            Language::Astro => self
+            Language::Astro | Language::Mdx => self
                 .try_parse(Parser::parse_astro_attr)
                 .map(Attribute::Astro)
                 .or_else(|_| self.parse_native_attr().map(Attribute::Native)),
-            Language::Jinja => {
+            Language::Jinja
+            | Language::Liquid
+            | Language::Django
+            | Language::GoTemplate
+            | Language::Erb
+            | Language::Ejs
+            | Language::Php
+            | Language::Heex
+            | Language::Custom(..) => {
                 self.skip_ws();
-                let result = if matches!(self.chars.peek(), Some((_, '{'))) {
-                    let mut chars = self.chars.clone();
-                    chars.next();
-                    match chars.next() {
-                        Some((_, '{')) => self.parse_native_attr().map(Attribute::Native),
-                        Some((_, '#')) => self.parse_jinja_comment().map(Attribute::JinjaComment),
-                        _ => self.parse_jinja_tag_or_block(None, &mut Parser::parse_attr),
+                let result = match self
+                    .language
+                    .dialect()
+                    .and_then(|dialect| self.peek_dialect_delimiter(dialect))
+                {
+                    Some(DialectDelimiter::Comment) => {
+                        self.parse_jinja_comment().map(Attribute::JinjaComment)
+                    }
+                    Some(DialectDelimiter::Tag) => {
+                        self.parse_jinja_tag_or_block(None, &mut Parser::parse_attr)
+                    }
+                    Some(DialectDelimiter::Interpolation) | None
+                        if matches!(self.language, Language::Heex) =>
+                    {
+                        self.try_parse(Parser::parse_heex_attr)
+                            .map(Attribute::Heex)
+                            .or_else(|_| self.parse_native_attr().map(Attribute::Native))
+                    }
+                    Some(DialectDelimiter::Interpolation) | None => {
+                        self.parse_native_attr().map(Attribute::Native)
                     }
-                } else {
-                    self.parse_native_attr().map(Attribute::Native)
                 };
                 if result.is_ok() {
                     self.skip_ws();
@@ -740,9 +844,33 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_attr_name(&mut self) -> PResult<&'s str> {
-        if matches!(
+        if let Some(dialect) = self.language.dialect() {
+            let start = self.peek_pos();
+            let mut end = start;
+            loop {
+                match self.peek_dialect_delimiter(dialect) {
+                    Some(DialectDelimiter::Interpolation) => {
+                        self.parse_dialect_interpolation(dialect)?;
+                        end = self.peek_pos();
+                    }
+                    Some(DialectDelimiter::Tag | DialectDelimiter::Comment) => break,
+                    None => match self.chars.peek() {
+                        Some((i, c)) if is_attr_name_char(*c) => {
+                            end = *i + c.len_utf8();
+                            self.chars.next();
+                        }
+                        _ => break,
+                    },
+                }
+            }
+            if start == end {
+                return Err(self.emit_error(SyntaxErrorKind::ExpectAttrName));
+            }
+
+            unsafe { Ok(self.source.get_unchecked(start..end)) }
+        } else if matches!(
             self.language,
-            Language::Jinja | Language::Vento | Language::Mustache
+            Language::Vento | Language::Mustache | Language::Glimmer
         ) {
             let Some((start, mut end)) = (match self.chars.peek() {
                 Some((i, '{')) => {
@@ -811,14 +939,51 @@ impl<'s> Parser<'s> {
         let quote = self.chars.next_if(|(_, c)| *c == '"' || *c == '\'');
 
         if let Some((start, quote)) = quote {
+            let start = start + 1;
+            if let Some(dialect) = self.language.dialect() {
+                let mut closing = None;
+                let end = loop {
+                    if let Some(close) = closing {
+                        if self.try_consume_str(close).is_some() {
+                            closing = None;
+                            continue;
+                        }
+                    } else if let Some(delimiter) = self.peek_dialect_delimiter(dialect) {
+                        let (open, close) = delimiter.of(dialect);
+                        self.try_consume_str(open);
+                        closing = Some(close);
+                        continue;
+                    }
+                    match self.chars.next() {
+                        Some((i, c)) if c == quote && closing.is_none() => break i,
+                        // string literal in template code may contain delimiters or quotes
+                        Some((_, c @ ('"' | '\''))) if closing.is_some() => {
+                            while self.chars.next_if(|(_, next)| *next != c).is_some() {}
+                            self.chars.next();
+                        }
+                        Some(..) => continue,
+                        None => break start,
+                    }
+                };
+                return Ok((unsafe { self.source.get_unchecked(start..end) }, start));
+            }
+
             let can_interpolate = matches!(
                 self.language,
-                Language::Svelte | Language::Jinja | Language::Vento | Language::Mustache
+                Language::Svelte | Language::Vento | Language::Mustache | Language::Glimmer
             );
-            let start = start + 1;
             let mut end = start;
             let mut chars_stack = vec![];
             loop {
+                // Razor expressions in attribute value can contain quotes, like `@Url.Action("Index")`
+                if self.language == Language::Razor
+                    && let Some((i, '@')) = self.chars.peek().copied()
+                    && is_razor_transition_at(self.source, i)
+                    && !self.source[i + 1..].starts_with(['{', '*'])
+                    && self.try_parse(Parser::parse_razor_expr).is_ok()
+                {
+                    continue;
+                }
                 match self.chars.next() {
                     Some((i, c)) if c == quote => {
                         if chars_stack.is_empty() || !can_interpolate {
@@ -854,13 +1019,48 @@ impl<'s> Parser<'s> {
                 _ => return Err(self.emit_error(SyntaxErrorKind::ExpectAttrValue)),
             };
 
+            if let Some(dialect) = self.language.dialect() {
+                let mut end = start;
+                loop {
+                    match self.peek_dialect_delimiter(dialect) {
+                        Some(DialectDelimiter::Interpolation) => {
+                            self.parse_dialect_interpolation(dialect)?;
+                        }
+                        Some(DialectDelimiter::Tag) => {
+                            if self
+                                .try_parse(|parser| {
+                                    parser.parse_jinja_tag_or_block(None, &mut Parser::parse_node)
+                                })
+                                .is_err()
+                            {
+                                self.chars.next();
+                            }
+                        }
+                        Some(DialectDelimiter::Comment) => {
+                            self.parse_jinja_comment()?;
+                        }
+                        None => {
+                            if self
+                                .chars
+                                .next_if(|(_, c)| is_unquoted_attr_value_char(*c))
+                                .is_none()
+                            {
+                                break;
+                            }
+                        }
+                    }
+                    end = self.peek_pos();
+                }
+                return Ok((unsafe { self.source.get_unchecked(start..end) }, start));
+            }
+
             let mut end = start;
             loop {
                 match self.chars.peek() {
                     Some((i, '{'))
                         if matches!(
                             self.language,
-                            Language::Jinja | Language::Vento | Language::Mustache
+                            Language::Vento | Language::Mustache | Language::Glimmer
                         ) =>
                     {
                         end = *i;
@@ -900,6 +1100,181 @@ impl<'s> Parser<'s> {
         }
     }
 
+    fn parse_blade_comment(&mut self) -> PResult<BladeComment<'s>> {
+        if self.try_consume_str("{{--").is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectComment));
+        }
+        let (raw, _) = self.parse_until_str("--}}");
+        Ok(BladeComment { raw })
+    }
+
+    fn parse_blade_directive(&mut self) -> PResult<BladeDirective<'s>> {
+        let Some((start, _)) = self.chars.next_if(|(_, c)| *c == '@') else {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectBladeDirective));
+        };
+        let mut end = start;
+        while let Some((i, _)) = self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            end = i;
+        }
+        if end == start {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectBladeDirective));
+        }
+        let name = unsafe { self.source.get_unchecked(start + 1..=end) };
+
+        let mut chars = self.chars.clone();
+        while chars.next_if(|(_, c)| matches!(c, ' ' | '\t')).is_some() {}
+        let Some((args_start, _)) = chars.next_if(|(_, c)| *c == '(') else {
+            return Ok(BladeDirective { name, args: None });
+        };
+        self.chars = chars;
+        let args_start = args_start + 1;
+        let mut depth = 0u16;
+        loop {
+            match self.chars.next() {
+                Some((_, '(')) => depth += 1,
+                Some((i, ')')) => {
+                    if depth == 0 {
+                        return Ok(BladeDirective {
+                            name,
+                            args: Some((
+                                unsafe { self.source.get_unchecked(args_start..i) },
+                                args_start,
+                            )),
+                        });
+                    }
+                    depth -= 1;
+                }
+                Some((_, quote @ ('\'' | '"'))) => {
+                    while let Some((_, c)) = self.chars.next() {
+                        if c == '\\' {
+                            self.chars.next();
+                        } else if c == quote {
+                            break;
+                        }
+                    }
+                }
+                Some(..) => {}
+                None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar(')'))),
+            }
+        }
+    }
+
+    fn parse_blade_directive_or_block(&mut self) -> PResult<NodeKind<'s>> {
+        let block_start = self.peek_pos();
+        let directive = self.parse_blade_directive()?;
+        if !helpers::is_blade_block_start(directive.name, directive.args.map(|(args, _)| args)) {
+            return Ok(NodeKind::BladeDirective(directive));
+        }
+        let block_name = directive.name;
+        let block_error = |parser: &mut Self| {
+            let (line, column) = helpers::pos_to_line_col(parser.source, block_start);
+            parser.emit_error(SyntaxErrorKind::ExpectBladeBlockEnd {
+                directive: block_name.into(),
+                line,
+                column,
+            })
+        };
+
+        if matches!(block_name, "php" | "verbatim") {
+            // content is kept as is until the end directive
+            let start = self.peek_pos();
+            let end_directive = format!("@end{block_name}");
+            let end = loop {
+                let pos = self.peek_pos();
+                if self.try_consume_str(&end_directive).is_some() {
+                    break pos;
+                } else if self.chars.next().is_none() {
+                    return Err(block_error(self));
+                }
+            };
+            let raw = unsafe { self.source.get_unchecked(start..end) };
+            return Ok(NodeKind::BladeBlock(BladeBlock {
+                directives: vec![
+                    directive,
+                    BladeDirective {
+                        name: unsafe { self.source.get_unchecked(end + 1..self.peek_pos()) },
+                        args: None,
+                    },
+                ],
+                children: vec![vec![Node {
+                    kind: NodeKind::Text(TextNode {
+                        raw,
+                        line_breaks: raw.chars().filter(|c| *c == '\n').count(),
+                        start,
+                    }),
+                    raw,
+                    start,
+                }]],
+            }));
+        }
+
+        let mut directives = vec![directive];
+        let mut children = vec![vec![]];
+        loop {
+            match self.chars.peek() {
+                Some((i, '@')) if is_blade_directive_at(self.source, *i) => {
+                    let start = self.peek_pos();
+                    let chars = self.chars.clone();
+                    let directive = self.parse_blade_directive()?;
+                    if helpers::is_blade_block_end(directive.name, block_name) {
+                        directives.push(directive);
+                        return Ok(NodeKind::BladeBlock(BladeBlock {
+                            directives,
+                            children,
+                        }));
+                    } else if helpers::is_blade_block_branch(directive.name, block_name) {
+                        directives.push(directive);
+                        children.push(vec![]);
+                    } else {
+                        self.chars = chars;
+                        let kind = self.parse_blade_directive_or_block()?;
+                        let end = self.peek_pos();
+                        if let Some(nodes) = children.last_mut() {
+                            nodes.push(Node {
+                                kind,
+                                raw: unsafe { self.source.get_unchecked(start..end) },
+                                start,
+                            });
+                        }
+                    }
+                }
+                Some(..) => {
+                    let node = self.parse_node()?;
+                    if let Some(nodes) = children.last_mut() {
+                        nodes.push(node);
+                    }
+                }
+                None => return Err(block_error(self)),
+            }
+        }
+    }
+
+    /// Skips `{{ ... }}` after `@` which won't be compiled by Blade.
+    fn skip_blade_escaped_echo(&mut self) {
+        if self.peek_str("{{") {
+            self.parse_until_str("}}");
+        }
+    }
+
+    fn parse_blade_echo(&mut self) -> PResult<BladeEcho<'s>> {
+        let (escaped, close) = if self.try_consume_str("{{").is_some() {
+            (true, "}}")
+        } else if self.try_consume_str("{!!").is_some() {
+            (false, "!!}")
+        } else {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectBladeEcho));
+        };
+        let (expr, start) = self.parse_until_str(close);
+        Ok(BladeEcho {
+            expr,
+            start,
+            escaped,
+        })
+    }
+
     fn parse_cdata(&mut self) -> PResult<Cdata<'s>> {
         let Some((start, _)) = self.try_consume_str("<![CDATA[") else {
             return Err(self.emit_error(SyntaxErrorKind::ExpectCdata));
@@ -1064,18 +1439,28 @@ impl<'s> Parser<'s> {
             && (tag_name.eq_ignore_ascii_case("script")
                 || tag_name.eq_ignore_ascii_case("style")
                 || tag_name.eq_ignore_ascii_case("pre")
-                || tag_name.eq_ignore_ascii_case("textarea"));
+                || tag_name.eq_ignore_ascii_case("textarea")
+                || self.language == Language::Vue
+                    && self.state.element_depth == 0
+                    && helpers::is_vue_custom_block(tag_name)
+                || matches!(self.language, Language::Vue | Language::Svelte)
+                    && self.state.element_depth == 0
+                    && helpers::is_non_html_template(tag_name, &attrs));
         if should_parse_raw {
             let text_node = self.parse_raw_text_node(tag_name)?;
             let raw = text_node.raw;
+            let start = text_node.start;
             if !raw.is_empty() {
                 children.push(Node {
                     kind: NodeKind::Text(text_node),
                     raw,
+                    start,
                 });
             }
         }
 
+        let razor_code_context = mem::replace(&mut self.state.razor_code_context, false);
+        self.state.element_depth += 1;
         loop {
             match self.chars.peek() {
                 Some((_, '<')) => {
@@ -1110,13 +1495,29 @@ impl<'s> Parser<'s> {
                     children.push(self.parse_node()?);
                 }
                 Some(..) => {
-                    if should_parse_raw {
+                    if self.language == Language::Mdx
+                        && !should_parse_raw
+                        && let Some((whitespace, markdown)) = self.parse_mdx_markdown_in_jsx()
+                    {
+                        children.push(Node {
+                            raw: whitespace.raw,
+                            start: whitespace.start,
+                            kind: NodeKind::Text(whitespace),
+                        });
+                        children.push(Node {
+                            raw: markdown.raw,
+                            start: markdown.start,
+                            kind: NodeKind::MdxMarkdown(markdown),
+                        });
+                    } else if should_parse_raw {
                         let text_node = self.parse_raw_text_node(tag_name)?;
                         let raw = text_node.raw;
+                        let start = text_node.start;
                         if !raw.is_empty() {
                             children.push(Node {
                                 kind: NodeKind::Text(text_node),
                                 raw,
+                                start,
                             });
                         }
                     } else {
@@ -1133,6 +1534,8 @@ impl<'s> Parser<'s> {
                 }
             }
         }
+        self.state.razor_code_context = razor_code_context;
+        self.state.element_depth -= 1;
 
         Ok(Element {
             tag_name,
@@ -1221,6 +1624,141 @@ impl<'s> Parser<'s> {
         })
     }
 
+    fn parse_heex_attr(&mut self) -> PResult<HeexAttribute<'s>> {
+        let attr_start = self.peek_pos();
+        let name = if self.chars.next_if(|(_, c)| *c == '{').is_some() {
+            None
+        } else {
+            let name = self.parse_attr_name()?;
+            self.skip_ws();
+            if self
+                .chars
+                .next_if(|(_, c)| *c == '=')
+                .map(|_| self.skip_ws())
+                .and_then(|_| self.chars.next_if(|(_, c)| *c == '{'))
+                .is_none()
+            {
+                return Err(self.emit_error(SyntaxErrorKind::ExpectHeexAttr));
+            }
+            Some(name)
+        };
+        let start = self.peek_pos();
+        let end = self.skip_elixir_code()?;
+        Ok(HeexAttribute {
+            name,
+            expr: (unsafe { self.source.get_unchecked(start..end) }, start),
+            start: attr_start,
+        })
+    }
+
+    /// Skip Elixir code until the unmatched `}` and return its position.
+    fn skip_elixir_code(&mut self) -> PResult<usize> {
+        let mut depth = 0u16;
+        let mut prev = ' ';
+        loop {
+            match self.chars.next() {
+                Some((i, '}')) => {
+                    if depth == 0 {
+                        return Ok(i);
+                    }
+                    depth -= 1;
+                }
+                Some((_, '{')) => depth += 1,
+                Some((i, quote @ ('"' | '\''))) => self.skip_elixir_string(i, quote, true)?,
+                Some((_, '~'))
+                    if self
+                        .chars
+                        .peek()
+                        .is_some_and(|(_, c)| c.is_ascii_alphabetic()) =>
+                {
+                    // lowercase sigils support interpolation, while uppercase ones don't
+                    let interpolated = self
+                        .chars
+                        .next_if(|(_, c)| c.is_ascii_lowercase())
+                        .is_some();
+                    while self
+                        .chars
+                        .next_if(|(_, c)| c.is_ascii_uppercase())
+                        .is_some()
+                    {}
+                    let close = match self.chars.next() {
+                        Some((_, '(')) => ')',
+                        Some((_, '[')) => ']',
+                        Some((_, '{')) => '}',
+                        Some((_, '<')) => '>',
+                        Some((i, quote @ ('"' | '\''))) => {
+                            self.skip_elixir_string(i, quote, interpolated)?;
+                            continue;
+                        }
+                        Some((_, c @ ('/' | '|'))) => c,
+                        _ => return Err(self.emit_error(SyntaxErrorKind::ExpectChar('}'))),
+                    };
+                    self.skip_elixir_string(0, close, interpolated)?;
+                }
+                // character literal like `?}`, but not the `?` at the end of identifiers
+                Some((_, '?')) if !(prev.is_ascii_alphanumeric() || prev == '_') => {
+                    if let Some((_, '\\')) = self.chars.next() {
+                        self.chars.next();
+                    }
+                }
+                Some((_, '#')) => while self.chars.next_if(|(_, c)| *c != '\n').is_some() {},
+                Some((_, c)) => {
+                    prev = c;
+                    continue;
+                }
+                None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar('}'))),
+            }
+            prev = ' ';
+        }
+    }
+
+    /// Skip Elixir string or sigil content after the opening delimiter at `start`,
+    /// including heredocs which are delimited by triple quotes.
+    fn skip_elixir_string(&mut self, start: usize, close: char, interpolated: bool) -> PResult<()> {
+        let rest_quotes = match close {
+            '"' => "\"\"",
+            '\'' => "''",
+            _ => "",
+        };
+        let heredoc = !rest_quotes.is_empty()
+            && self
+                .source
+                .get(start + 1..)
+                .is_some_and(|rest| rest.starts_with(rest_quotes));
+        if heredoc {
+            self.chars.next();
+            self.chars.next();
+        }
+        loop {
+            match self.chars.next() {
+                Some((_, '\\')) => {
+                    self.chars.next();
+                }
+                Some((i, c)) if c == close => {
+                    if !heredoc {
+                        return Ok(());
+                    }
+                    if self
+                        .source
+                        .get(i + 1..)
+                        .is_some_and(|rest| rest.starts_with(rest_quotes))
+                    {
+                        self.chars.next();
+                        self.chars.next();
+                        return Ok(());
+                    }
+                }
+                Some((_, '#'))
+                    if interpolated && self.chars.next_if(|(_, c)| *c == '{').is_some() =>
+                {
+                    self.skip_elixir_code()?;
+                }
+                Some(..) => {}
+                None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar(close))),
+            }
+        }
+    }
+
     fn parse_identifier(&mut self) -> PResult<&'s str> {
         fn is_identifier_char(c: char) -> bool {
             c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() || c == '\\'
@@ -1273,17 +1811,16 @@ impl<'s> Parser<'s> {
         T: HasJinjaFlowControl<'s>,
         F: FnMut(&mut Self) -> PResult<T>,
     {
+        let dialect = self.jinja_dialect();
         let mut children = vec![];
         loop {
+            if matches!(
+                self.peek_dialect_delimiter(dialect),
+                Some(DialectDelimiter::Tag)
+            ) {
+                break;
+            }
             match self.chars.peek() {
-                Some((_, '{')) => {
-                    let mut chars = self.chars.clone();
-                    chars.next();
-                    if chars.next_if(|(_, c)| *c == '%').is_some() {
-                        break;
-                    }
-                    children.push(children_parser(self)?);
-                }
                 Some((_, c)) if c.is_ascii_whitespace() && T::skip_ws_before_jinja_block_end() => {
                     self.chars.next();
                 }
@@ -1304,59 +1841,107 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_jinja_comment(&mut self) -> PResult<JinjaComment<'s>> {
-        let Some((start, _)) = self.try_consume_str("{#") else {
+        let (open, close) = self.jinja_dialect().comment();
+        if self.try_consume_str(open).is_none() {
             return Err(self.emit_error(SyntaxErrorKind::ExpectComment));
+        }
+        let (raw, _) = self.parse_until_str(close);
+        Ok(JinjaComment { raw })
+    }
+
+    fn parse_jinja_tag(&mut self) -> PResult<JinjaTag<'s>> {
+        let (open, close) = self.jinja_dialect().tag();
+        if self.try_consume_str(open).is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectJinjaTag));
+        }
+        let start = self.peek_pos();
+        if self.language == Language::GoTemplate
+            && self.source[start..]
+                .strip_prefix('-')
+                .unwrap_or(&self.source[start..])
+                .trim_start()
+                .starts_with("/*")
+        {
+            // closing delimiter can appear in comment
+            self.parse_until_str("*/");
+        }
+        let (rest, rest_start) = self.parse_until_str(close);
+        let content = unsafe { self.source.get_unchecked(start..rest_start + rest.len()) };
+        Ok(JinjaTag { content, start })
+    }
+
+    /// Returns the dialect for parsing Jinja-like syntax.
+    /// This falls back to Jinja for other languages.
+    fn jinja_dialect(&self) -> &'static dyn Dialect {
+        self.language.dialect().unwrap_or(&dialect::Jinja)
+    }
+
+    /// Consumes until the given string or EOF, and the given string itself.
+    fn parse_until_str(&mut self, s: &str) -> (&'s str, usize) {
+        let start = self.peek_pos();
+        let end = loop {
+            let pos = self.peek_pos();
+            if self.try_consume_str(s).is_some() || self.chars.next().is_none() {
+                break pos;
+            }
         };
-        let start = start + 1;
+        (unsafe { self.source.get_unchecked(start..end) }, start)
+    }
 
+    fn parse_dialect_interpolation(&mut self, dialect: &dyn Dialect) -> PResult<(&'s str, usize)> {
+        let (open, close) = dialect.interpolation();
+        if self.try_consume_str(open).is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectMustacheInterpolation));
+        }
+        let start = self.peek_pos();
         let end;
+
+        let mut pair_stack = vec![];
         loop {
+            if pair_stack.is_empty() {
+                let pos = self.peek_pos();
+                if self.try_consume_str(close).is_some() {
+                    end = pos;
+                    break;
+                }
+            }
             match self.chars.next() {
-                Some((i, '#')) => {
-                    let mut chars = self.chars.clone();
-                    if chars.next_if(|(_, c)| *c == '}').is_some() {
+                Some((_, c @ ('{' | '[' | '('))) => {
+                    if !matches!(pair_stack.last(), Some('\'' | '"' | '`')) {
+                        pair_stack.push(c);
+                    }
+                }
+                Some((i, c @ ('}' | ']' | ')'))) => {
+                    if pair_stack.last().is_some_and(|last| {
+                        matches!((last, c), ('{', '}') | ('[', ']') | ('(', ')'))
+                    }) {
+                        pair_stack.pop();
+                    } else if let Some(rest) = close.strip_prefix(c)
+                        && pair_stack.is_empty()
+                    {
+                        // unterminated interpolation ends at unpaired bracket
                         end = i;
-                        self.chars = chars;
+                        self.try_consume_str(rest);
                         break;
                     }
                 }
-                Some(..) => continue,
-                None => {
-                    end = self.source.len();
-                    break;
-                }
-            }
-        }
-
-        Ok(JinjaComment {
-            raw: unsafe { self.source.get_unchecked(start..end) },
-        })
-    }
-
-    fn parse_jinja_tag(&mut self) -> PResult<JinjaTag<'s>> {
-        let Some((start, _)) = self.try_consume_str("{%") else {
-            return Err(self.emit_error(SyntaxErrorKind::ExpectJinjaTag));
-        };
-        let start = start + 1;
-
-        let mut end = start;
-        loop {
-            match self.chars.next() {
-                Some((i, '%')) => {
-                    if self.chars.next_if(|(_, c)| *c == '}').is_some() {
-                        end = i;
-                        break;
+                Some((_, c @ ('\'' | '"' | '`'))) => match pair_stack.last() {
+                    Some(last) if *last == c => {
+                        pair_stack.pop();
                     }
-                }
+                    Some('{' | '[' | '(') | None => {
+                        pair_stack.push(c);
+                    }
+                    _ => {}
+                },
                 Some(..) => continue,
-                None => break,
+                None => {
+                    end = self.source.len();
+                    break;
+                }
             }
         }
-
-        Ok(JinjaTag {
-            content: unsafe { self.source.get_unchecked(start..end) },
-            start,
-        })
+        Ok((unsafe { self.source.get_unchecked(start..end) }, start))
     }
 
     fn parse_jinja_tag_or_block<T, F>(
@@ -1373,68 +1958,54 @@ impl<'s> Parser<'s> {
         } else {
             self.parse_jinja_tag()?
         };
-        let tag_name = parse_jinja_tag_name(&first_tag);
+        let dialect = self.jinja_dialect();
+        let tag_name = dialect.tag_name(first_tag.content);
 
-        if matches!(
-            tag_name,
-            "for"
-                | "if"
-                | "macro"
-                | "call"
-                | "filter"
-                | "block"
-                | "apply"
-                | "autoescape"
-                | "embed"
-                | "with"
-                | "trans"
-                | "raw"
-        ) || tag_name == "set" && !first_tag.content.contains('=')
-        {
+        if dialect.is_block_start(tag_name, first_tag.content) {
             let tag_start = first_tag.start;
             let mut body = vec![JinjaTagOrChildren::Tag(first_tag)];
 
-            loop {
-                let mut children =
-                    self.parse_jinja_block_children(tag_name, tag_start, children_parser)?;
-                if !children.is_empty() {
-                    if let Some(JinjaTagOrChildren::Children(nodes)) = body.last_mut() {
-                        nodes.append(&mut children);
-                    } else {
-                        body.push(JinjaTagOrChildren::Children(children));
-                    }
+            if dialect.is_raw_block(tag_name) || dialect.embedded_code(tag_name).is_some() {
+                let chars = self.chars.clone();
+                let start = self.peek_pos();
+                if let Ok((end, end_tag)) = self.parse_jinja_raw_block_end(tag_name, tag_start)
+                    && let Some(text) = T::build_raw_text(TextNode {
+                        raw: unsafe { self.source.get_unchecked(start..end) },
+                        line_breaks: 0,
+                        start,
+                    })
+                {
+                    body.push(JinjaTagOrChildren::Children(vec![text]));
+                    body.push(JinjaTagOrChildren::Tag(end_tag));
+                    return Ok(T::from_block(JinjaBlock { body }));
                 }
-                if let Ok(next_tag) = self.parse_jinja_tag() {
-                    let next_tag_name = parse_jinja_tag_name(&next_tag);
-                    if next_tag_name
-                        .strip_prefix("end")
-                        .is_some_and(|name| name == tag_name)
-                    {
-                        body.push(JinjaTagOrChildren::Tag(next_tag));
-                        break;
-                    }
-                    if (tag_name == "if" || tag_name == "for")
-                        && matches!(next_tag_name, "elif" | "elseif" | "else")
-                    {
-                        body.push(JinjaTagOrChildren::Tag(next_tag));
-                    } else if let Some(JinjaTagOrChildren::Children(nodes)) = body.last_mut() {
-                        nodes.push(
-                            self.with_taken(|parser| {
-                                parser.parse_jinja_tag_or_block(Some(next_tag), children_parser)
-                            })
-                            .map(|(kind, raw)| T::build(kind, raw))?,
-                        );
-                    } else {
-                        body.push(JinjaTagOrChildren::Children(vec![
-                            self.with_taken(|parser| {
-                                parser.parse_jinja_tag_or_block(Some(next_tag), children_parser)
-                            })
-                            .map(|(kind, raw)| T::build(kind, raw))?,
-                        ]));
-                    }
-                } else {
-                    break;
+                self.chars = chars;
+            }
+
+            let chars = self.chars.clone();
+            let start = self.peek_pos();
+            match self.parse_jinja_block_body(tag_name, tag_start, &mut body, children_parser) {
+                Ok(()) => {}
+                // elements in PHP templates can be opened and closed in different blocks,
+                // so the whole block is kept as is if it can't be parsed
+                Err(error) if self.language == Language::Php => {
+                    self.chars = chars;
+                    body.truncate(1);
+                    let Ok((end, end_tag)) = self.parse_jinja_raw_block_end(tag_name, tag_start)
+                    else {
+                        return Err(error);
+                    };
+                    let Some(text) = T::build_raw_text(TextNode {
+                        raw: unsafe { self.source.get_unchecked(start..end) },
+                        line_breaks: 0,
+                        start,
+                    }) else {
+                        return Err(error);
+                    };
+                    body.push(JinjaTagOrChildren::Children(vec![text]));
+                    body.push(JinjaTagOrChildren::Tag(end_tag));
                 }
+                Err(error) => return Err(error),
             }
             Ok(T::from_block(JinjaBlock { body }))
         } else {
@@ -1442,6 +2013,97 @@ impl<'s> Parser<'s> {
         }
     }
 
+    /// Parses children, branches and the closing tag of a block, after its opening tag.
+    fn parse_jinja_block_body<T, F>(
+        &mut self,
+        tag_name: &str,
+        tag_start: usize,
+        body: &mut Vec<JinjaTagOrChildren<'s, T>>,
+        children_parser: &mut F,
+    ) -> PResult<()>
+    where
+        T: HasJinjaFlowControl<'s>,
+        F: FnMut(&mut Self) -> PResult<T>,
+    {
+        let dialect = self.jinja_dialect();
+        loop {
+            let mut children =
+                self.parse_jinja_block_children(tag_name, tag_start, children_parser)?;
+            if !children.is_empty() {
+                if let Some(JinjaTagOrChildren::Children(nodes)) = body.last_mut() {
+                    nodes.append(&mut children);
+                } else {
+                    body.push(JinjaTagOrChildren::Children(children));
+                }
+            }
+            if let Ok(next_tag) = self.parse_jinja_tag() {
+                let next_tag_name = dialect.tag_name(next_tag.content);
+                if dialect.is_block_end(next_tag_name, tag_name) {
+                    body.push(JinjaTagOrChildren::Tag(next_tag));
+                    break;
+                }
+                if dialect.is_block_branch(next_tag_name, tag_name) {
+                    body.push(JinjaTagOrChildren::Tag(next_tag));
+                } else if let Some(JinjaTagOrChildren::Children(nodes)) = body.last_mut() {
+                    nodes.push(
+                        self.with_taken(|parser| {
+                            parser.parse_jinja_tag_or_block(Some(next_tag), children_parser)
+                        })
+                        .map(|(kind, raw, start)| T::build(kind, raw, start))?,
+                    );
+                } else {
+                    body.push(JinjaTagOrChildren::Children(vec![
+                        self.with_taken(|parser| {
+                            parser.parse_jinja_tag_or_block(Some(next_tag), children_parser)
+                        })
+                        .map(|(kind, raw, start)| T::build(kind, raw, start))?,
+                    ]));
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes until the tag which closes the block, ignoring everything else.
+    fn parse_jinja_raw_block_end(
+        &mut self,
+        block_name: &str,
+        tag_start: usize,
+    ) -> PResult<(usize, JinjaTag<'s>)> {
+        let dialect = self.jinja_dialect();
+        let (tag_open, _) = dialect.tag();
+        // blocks nested in raw block are just text, but others must be balanced
+        let is_raw = dialect.is_raw_block(block_name);
+        let mut nested_blocks: Vec<&str> = vec![];
+        loop {
+            let pos = self.peek_pos();
+            if self.peek_str(tag_open)
+                && let Ok(tag) = self.parse_jinja_tag()
+            {
+                let tag_name = dialect.tag_name(tag.content);
+                if let Some(nested) = nested_blocks.last() {
+                    if dialect.is_block_end(tag_name, nested) {
+                        nested_blocks.pop();
+                    }
+                } else if dialect.is_block_end(tag_name, block_name) {
+                    return Ok((pos, tag));
+                }
+                if !is_raw && dialect.is_block_start(tag_name, tag.content) {
+                    nested_blocks.push(tag_name);
+                }
+            } else if self.chars.next().is_none() {
+                let (line, column) = helpers::pos_to_line_col(self.source, tag_start);
+                return Err(self.emit_error(SyntaxErrorKind::ExpectJinjaBlockEnd {
+                    tag_name: block_name.into(),
+                    line,
+                    column,
+                }));
+            }
+        }
+    }
+
     // former `/` has been consumed
     fn parse_js_comment(&mut self) -> PResult<JsComment<'s>> {
         let Some((start, first_char)) = self.chars.next_if(|(_, c)| matches!(c, '/' | '*')) else {
@@ -1531,13 +2193,19 @@ impl<'s> Parser<'s> {
                 }
                 let node = self.parse_node()?;
                 if let NodeKind::MustacheInterpolation(interpolation) = &node.kind
-                    && let ("else", wc_before, wc_after) =
+                    && let (content, wc_before, wc_after) =
                         strip_hbs_whitespace_control(interpolation.content)
+                    && let Some(rest) = content.strip_prefix("else").filter(|rest| {
+                        rest.is_empty()
+                            // Glimmer chains blocks like `{{else if cond}}`
+                            || matches!(self.language, Language::Glimmer)
+                                && rest.starts_with(|c: char| c.is_ascii_whitespace())
+                    })
                 {
                     controls.push(MustacheBlockControl {
                         name: "else",
                         prefix: "",
-                        content: None,
+                        content: Some(rest).filter(|rest| !rest.is_empty()),
                         wc_before,
                         wc_after,
                     });
@@ -1568,7 +2236,19 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_native_attr(&mut self) -> PResult<NativeAttribute<'s>> {
-        let name = self.parse_attr_name()?;
+        let start = self.peek_pos();
+        let mut name = self.parse_attr_name()?;
+        if self.language == Language::Glimmer && name == "as" {
+            // block params like `as |item index|` are parsed as one attribute
+            let mut chars = self.chars.clone();
+            while chars.next_if(|(_, c)| c.is_ascii_whitespace()).is_some() {}
+            if chars.next_if(|(_, c)| *c == '|').is_some()
+                && let Some((end, _)) = chars.find(|(_, c)| *c == '|')
+            {
+                self.chars = chars;
+                name = unsafe { self.source.get_unchecked(start..end + 1) };
+            }
+        }
         self.skip_ws();
         let mut quote = None;
         let value = if self.chars.next_if(|(_, c)| *c == '=').is_some() {
@@ -1581,15 +2261,61 @@ impl<'s> Parser<'s> {
         } else {
             None
         };
-        Ok(NativeAttribute { name, value, quote })
+        Ok(NativeAttribute {
+            name,
+            value,
+            quote,
+            start,
+        })
     }
 
     fn parse_node(&mut self) -> PResult<Node<'s>> {
-        let (kind, raw) = self.with_taken(Parser::parse_node_kind)?;
-        Ok(Node { kind, raw })
+        let (kind, raw, start) = self.with_taken(Parser::parse_node_kind)?;
+        Ok(Node { kind, raw, start })
     }
 
     fn parse_node_kind(&mut self) -> PResult<NodeKind<'s>> {
+        if let Some(dialect) = self.language.dialect() {
+            match self.peek_dialect_delimiter(dialect) {
+                Some(DialectDelimiter::Comment) => {
+                    return self.parse_jinja_comment().map(NodeKind::JinjaComment);
+                }
+                Some(DialectDelimiter::Tag) => {
+                    return self.parse_jinja_tag_or_block(None, &mut Parser::parse_node);
+                }
+                Some(DialectDelimiter::Interpolation) => {
+                    // interpolation can open a block, like `<%= form_with do %>`
+                    let chars = self.chars.clone();
+                    if let Ok(tag) = self.parse_jinja_tag()
+                        && dialect.is_block_start(dialect.tag_name(tag.content), tag.content)
+                    {
+                        return self.parse_jinja_tag_or_block(Some(tag), &mut Parser::parse_node);
+                    }
+                    self.chars = chars;
+                    return self
+                        .parse_dialect_interpolation(dialect)
+                        .map(|(expr, start)| {
+                            let has_trim_marker = dialect.trim_markers().contains(&'-');
+                            let (trim_prev, expr) = match expr.strip_prefix('-') {
+                                Some(rest) if has_trim_marker => (true, rest),
+                                _ => (false, expr),
+                            };
+                            let (trim_next, expr) = match expr.strip_suffix('-') {
+                                Some(rest) if has_trim_marker => (true, rest),
+                                _ => (false, expr),
+                            };
+                            NodeKind::JinjaInterpolation(JinjaInterpolation {
+                                expr,
+                                start: if trim_prev { start + 1 } else { start },
+                                trim_prev,
+                                trim_next,
+                            })
+                        });
+                }
+                None => {}
+            }
+        }
+
         match self.chars.peek() {
             Some((_, '<')) => {
                 let mut chars = self.chars.clone();
@@ -1609,7 +2335,18 @@ impl<'s> Parser<'s> {
                                 | Language::Jinja
                                 | Language::Vento
                                 | Language::Mustache
+                                | Language::Glimmer
                                 | Language::Xml
+                                | Language::Liquid
+                                | Language::Django
+                                | Language::GoTemplate
+                                | Language::Erb
+                                | Language::Ejs
+                                | Language::Php
+                                | Language::Heex
+                                | Language::Blade
+                                | Language::Razor
+                                | Language::Custom(..)
                         ) {
                             self.try_parse(Parser::parse_comment)
                                 .map(NodeKind::Comment)
@@ -1634,57 +2371,58 @@ impl<'s> Parser<'s> {
                 let mut chars = self.chars.clone();
                 chars.next();
                 match chars.next() {
-                    Some((_, '{')) => {
-                        match self.language {
-                            Language::Html | Language::Xml => {
-                                self.parse_text_node().map(NodeKind::Text)
-                            }
-                            Language::Vue | Language::Jinja => self
-                                .parse_mustache_interpolation()
-                                .map(|(expr, start)| match self.language {
-                                    Language::Vue => {
-                                        NodeKind::VueInterpolation(VueInterpolation { expr, start })
-                                    }
-                                    Language::Jinja => {
-                                        let (trim_prev, expr) =
-                                            if let Some(rest) = expr.strip_prefix('-') {
-                                                (true, rest)
-                                            } else {
-                                                (false, expr)
-                                            };
-                                        let (trim_next, expr) =
-                                            if let Some(rest) = expr.strip_suffix('-') {
-                                                (true, rest)
-                                            } else {
-                                                (false, expr)
-                                            };
-                                        NodeKind::JinjaInterpolation(JinjaInterpolation {
-                                            expr,
-                                            start: if trim_prev { start + 1 } else { start },
-                                            trim_prev,
-                                            trim_next,
-                                        })
-                                    }
-                                    _ => unreachable!(),
-                                }),
-                            Language::Svelte => self
-                                .parse_svelte_interpolation()
-                                .map(NodeKind::SvelteInterpolation),
-                            Language::Astro => self.parse_astro_expr().map(NodeKind::AstroExpr),
-                            Language::Angular => self
-                                .try_parse(|parser| {
-                                    parser.parse_mustache_interpolation().map(|(expr, start)| {
-                                        NodeKind::AngularInterpolation(AngularInterpolation {
-                                            expr,
-                                            start,
-                                        })
+                    Some((_, '{')) => match self.language {
+                        Language::Html
+                        | Language::Xml
+                        | Language::Jinja
+                        | Language::Liquid
+                        | Language::Django
+                        | Language::GoTemplate
+                        | Language::Erb
+                        | Language::Ejs
+                        | Language::Php
+                        | Language::Heex
+                        | Language::Razor
+                        | Language::Custom(..) => self.parse_text_node().map(NodeKind::Text),
+                        Language::Vue => {
+                            self.parse_mustache_interpolation().map(|(expr, start)| {
+                                NodeKind::VueInterpolation(VueInterpolation { expr, start })
+                            })
+                        }
+                        Language::Svelte => self
+                            .parse_svelte_interpolation()
+                            .map(NodeKind::SvelteInterpolation),
+                        Language::Astro | Language::Mdx => {
+                            self.parse_astro_expr().map(NodeKind::AstroExpr)
+                        }
+                        Language::Angular => self
+                            .try_parse(|parser| {
+                                parser.parse_mustache_interpolation().map(|(expr, start)| {
+                                    NodeKind::AngularInterpolation(AngularInterpolation {
+                                        expr,
+                                        start,
                                     })
                                 })
-                                .or_else(|_| self.parse_text_node().map(NodeKind::Text)),
-                            Language::Vento => self.parse_vento_tag_or_block(None),
-                            Language::Mustache => self.parse_mustache_block_or_interpolation(),
+                            })
+                            .or_else(|_| self.parse_text_node().map(NodeKind::Text)),
+                        Language::Vento => self.parse_vento_tag_or_block(None),
+                        Language::Mustache | Language::Glimmer => {
+                            self.parse_mustache_block_or_interpolation()
                         }
-                    }
+                        Language::Blade => {
+                            if chars.next_if(|(_, c)| *c == '-').is_some()
+                                && chars.next_if(|(_, c)| *c == '-').is_some()
+                            {
+                                self.parse_blade_comment().map(NodeKind::BladeComment)
+                            } else {
+                                self.parse_blade_echo().map(NodeKind::BladeEcho)
+                            }
+                        }
+                    },
+                    Some((_, '!')) if matches!(self.language, Language::Blade) => self
+                        .try_parse(Parser::parse_blade_echo)
+                        .map(NodeKind::BladeEcho)
+                        .or_else(|_| self.parse_text_node().map(NodeKind::Text)),
                     Some((_, '#')) if matches!(self.language, Language::Svelte) => {
                         match chars.next() {
                             Some((_, 'i')) => {
@@ -1705,18 +2443,16 @@ impl<'s> Parser<'s> {
                             _ => self.parse_text_node().map(NodeKind::Text),
                         }
                     }
-                    Some((_, '#')) if matches!(self.language, Language::Jinja) => {
-                        self.parse_jinja_comment().map(NodeKind::JinjaComment)
-                    }
-                    Some((_, '@')) => self.parse_svelte_at_tag().map(NodeKind::SvelteAtTag),
-                    Some((_, '%')) if matches!(self.language, Language::Jinja) => {
-                        self.parse_jinja_tag_or_block(None, &mut Parser::parse_node)
+                    Some((_, '@')) if matches!(self.language, Language::Svelte) => {
+                        self.parse_svelte_at_tag().map(NodeKind::SvelteAtTag)
                     }
                     _ => match self.language {
                         Language::Svelte => self
                             .parse_svelte_interpolation()
                             .map(NodeKind::SvelteInterpolation),
-                        Language::Astro => self.parse_astro_expr().map(NodeKind::AstroExpr),
+                        Language::Astro | Language::Mdx => {
+                            self.parse_astro_expr().map(NodeKind::AstroExpr)
+                        }
                         _ => self.parse_text_node().map(NodeKind::Text),
                     },
                 }
@@ -1726,9 +2462,19 @@ impl<'s> Parser<'s> {
                     self.language,
                     Language::Html
                         | Language::Astro
+                        | Language::Mdx
                         | Language::Jinja
                         | Language::Vento
                         | Language::Mustache
+                        | Language::Glimmer
+                        | Language::Liquid
+                        | Language::Django
+                        | Language::GoTemplate
+                        | Language::Erb
+                        | Language::Ejs
+                        | Language::Php
+                        | Language::Heex
+                        | Language::Custom(..)
                 ) && !self.state.has_front_matter =>
             {
                 let mut chars = self.chars.clone();
@@ -1739,6 +2485,18 @@ impl<'s> Parser<'s> {
                     self.parse_text_node().map(NodeKind::Text)
                 }
             }
+            Some((i, '@'))
+                if matches!(self.language, Language::Blade)
+                    && is_blade_directive_at(self.source, *i) =>
+            {
+                self.parse_blade_directive_or_block()
+            }
+            Some((i, '@'))
+                if matches!(self.language, Language::Razor)
+                    && is_razor_transition_at(self.source, *i) =>
+            {
+                self.parse_razor_transition()
+            }
             Some((_, '@')) if matches!(self.language, Language::Angular) => {
                 let mut chars = self.chars.clone();
                 chars.next();
@@ -1817,7 +2575,294 @@ impl<'s> Parser<'s> {
         })
     }
 
+    /// Consumes balanced C# code inside `open` and `close`, including themselves.
+    fn parse_razor_balanced(&mut self, open: char, close: char) -> PResult<(&'s str, usize)> {
+        let Some((start, _)) = self.chars.next_if(|(_, c)| *c == open) else {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectChar(open)));
+        };
+        let start = start + open.len_utf8();
+        let mut depth = 0u16;
+        loop {
+            match self.chars.next() {
+                Some((i, c)) if c == close => {
+                    if depth == 0 {
+                        return Ok((unsafe { self.source.get_unchecked(start..i) }, start));
+                    }
+                    depth -= 1;
+                }
+                Some((_, c)) if c == open => depth += 1,
+                Some((_, quote @ ('\'' | '"'))) => {
+                    while let Some((_, c)) = self.chars.next() {
+                        if c == '\\' {
+                            self.chars.next();
+                        } else if c == quote || c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                // verbatim string, where quotes are escaped by doubling
+                Some((_, '@')) if self.chars.next_if(|(_, c)| *c == '"').is_some() => {
+                    while let Some((_, c)) = self.chars.next() {
+                        if c == '"' && self.chars.next_if(|(_, c)| *c == '"').is_none() {
+                            break;
+                        }
+                    }
+                }
+                Some((_, '/')) if self.chars.next_if(|(_, c)| *c == '/').is_some() => {
+                    while self.chars.next_if(|(_, c)| *c != '\n').is_some() {}
+                }
+                Some((_, '/')) if self.chars.next_if(|(_, c)| *c == '*').is_some() => {
+                    while let Some((_, c)) = self.chars.next() {
+                        if c == '*' && self.chars.next_if(|(_, c)| *c == '/').is_some() {
+                            break;
+                        }
+                    }
+                }
+                Some(..) => {}
+                None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar(close))),
+            }
+        }
+    }
+
+    fn parse_razor_block(&mut self) -> PResult<RazorBlock<'s>> {
+        if self.chars.next_if(|(_, c)| *c == '@').is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectRazorBlock));
+        }
+        let mut keyword = self.parse_razor_identifier()?;
+        let mut branches = vec![];
+        loop {
+            let header_start = self.peek_pos();
+            loop {
+                match self.chars.peek() {
+                    Some((_, '{')) => break,
+                    Some((_, '(')) => {
+                        self.parse_razor_balanced('(', ')')?;
+                    }
+                    Some(..) => {
+                        self.chars.next();
+                    }
+                    None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar('{'))),
+                }
+            }
+            let header = unsafe { self.source.get_unchecked(header_start..self.peek_pos()) };
+            let trimmed = header.trim_ascii_start();
+            let header = Some(trimmed.trim_ascii_end())
+                .filter(|header| !header.is_empty())
+                .map(|header| (header, header_start + header.len() - trimmed.len()));
+            branches.push(RazorBranch {
+                keyword,
+                header,
+                children: self.parse_razor_block_children()?,
+            });
+
+            let chars = self.chars.clone();
+            self.skip_ws();
+            let next = match keyword {
+                "if" | "else if" => self.parse_razor_identifier().ok().and_then(|name| {
+                    if name != "else" {
+                        None
+                    } else {
+                        let chars = self.chars.clone();
+                        self.skip_ws();
+                        if self.parse_razor_identifier().is_ok_and(|name| name == "if") {
+                            Some("else if")
+                        } else {
+                            self.chars = chars;
+                            Some("else")
+                        }
+                    }
+                }),
+                "try" | "catch" => self
+                    .parse_razor_identifier()
+                    .ok()
+                    .filter(|name| matches!(*name, "catch" | "finally")),
+                _ => None,
+            };
+            if let Some(next) = next {
+                keyword = next;
+            } else {
+                self.chars = chars;
+                return Ok(RazorBlock { branches });
+            }
+        }
+    }
+
+    fn parse_razor_block_children(&mut self) -> PResult<Vec<Node<'s>>> {
+        if self.chars.next_if(|(_, c)| *c == '{').is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectChar('{')));
+        }
+
+        let razor_code_context = mem::replace(&mut self.state.razor_code_context, true);
+        let mut children = vec![];
+        loop {
+            match self.chars.peek() {
+                Some((_, '}')) => {
+                    self.chars.next();
+                    self.state.razor_code_context = razor_code_context;
+                    return Ok(children);
+                }
+                Some(..) => children.push(self.parse_node()?),
+                None => return Err(self.emit_error(SyntaxErrorKind::ExpectChar('}'))),
+            }
+        }
+    }
+
+    fn parse_razor_code(&mut self) -> PResult<RazorCode<'s>> {
+        if self.chars.next_if(|(_, c)| *c == '@').is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectRazorBlock));
+        }
+        let keyword = if self.chars.peek().is_some_and(|(_, c)| *c == '{') {
+            None
+        } else {
+            let keyword = self.parse_razor_identifier()?;
+            self.skip_ws();
+            Some(keyword)
+        };
+        let (code, start) = self.parse_razor_balanced('{', '}')?;
+        Ok(RazorCode {
+            keyword,
+            code,
+            start,
+        })
+    }
+
+    fn parse_razor_comment(&mut self) -> PResult<RazorComment<'s>> {
+        if self.try_consume_str("@*").is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectRazorComment));
+        }
+        let (raw, _) = self.parse_until_str("*@");
+        Ok(RazorComment { raw })
+    }
+
+    fn parse_razor_directive(&mut self) -> PResult<RazorDirective<'s>> {
+        if self.chars.next_if(|(_, c)| *c == '@').is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectRazorExpr));
+        }
+        let name = self.parse_razor_identifier()?;
+        while self
+            .chars
+            .next_if(|(_, c)| matches!(c, ' ' | '\t'))
+            .is_some()
+        {}
+        let start = self.peek_pos();
+        while self.chars.next_if(|(_, c)| *c != '\n').is_some() {}
+        let value = unsafe { self.source.get_unchecked(start..self.peek_pos()) };
+        Ok(RazorDirective {
+            name,
+            value: value.trim_ascii_end(),
+        })
+    }
+
+    fn parse_razor_expr(&mut self) -> PResult<RazorExpr<'s>> {
+        if self.chars.next_if(|(_, c)| *c == '@').is_none() {
+            return Err(self.emit_error(SyntaxErrorKind::ExpectRazorExpr));
+        }
+        if self.chars.peek().is_some_and(|(_, c)| *c == '(') {
+            let (expr, start) = self.parse_razor_balanced('(', ')')?;
+            return Ok(RazorExpr {
+                expr,
+                start,
+                explicit: true,
+            });
+        }
+
+        let start = self.peek_pos();
+        if self.parse_razor_identifier()? == "await" {
+            while self.chars.next_if(|(_, c)| *c == ' ').is_some() {}
+            self.parse_razor_identifier()?;
+        }
+        loop {
+            let mut chars = self.chars.clone();
+            match chars.next() {
+                Some((_, '(')) => {
+                    self.parse_razor_balanced('(', ')')?;
+                }
+                Some((_, '[')) => {
+                    self.parse_razor_balanced('[', ']')?;
+                }
+                Some((_, '?')) if chars.next_if(|(_, c)| *c == '.').is_some() => {
+                    if chars
+                        .peek()
+                        .is_some_and(|(_, c)| c.is_ascii_alphabetic() || *c == '_')
+                    {
+                        self.chars = chars;
+                        self.parse_razor_identifier()?;
+                    } else {
+                        break;
+                    }
+                }
+                Some((_, '.'))
+                    if chars
+                        .peek()
+                        .is_some_and(|(_, c)| c.is_ascii_alphabetic() || *c == '_') =>
+                {
+                    self.chars = chars;
+                    self.parse_razor_identifier()?;
+                }
+                _ => break,
+            }
+        }
+        Ok(RazorExpr {
+            expr: unsafe { self.source.get_unchecked(start..self.peek_pos()) },
+            start,
+            explicit: false,
+        })
+    }
+
+    fn parse_razor_identifier(&mut self) -> PResult<&'s str> {
+        let start = self.peek_pos();
+        while self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+            .is_some()
+        {}
+        let end = self.peek_pos();
+        if start == end {
+            Err(self.emit_error(SyntaxErrorKind::ExpectIdentifier))
+        } else {
+            Ok(unsafe { self.source.get_unchecked(start..end) })
+        }
+    }
+
+    /// Parses Razor syntax after `@` transition.
+    fn parse_razor_transition(&mut self) -> PResult<NodeKind<'s>> {
+        let mut chars = self.chars.clone();
+        chars.next();
+        match chars.peek() {
+            Some((_, '*')) => return self.parse_razor_comment().map(NodeKind::RazorComment),
+            Some((_, '{')) => return self.parse_razor_code().map(NodeKind::RazorCode),
+            Some((_, '(')) => return self.parse_razor_expr().map(NodeKind::RazorExpr),
+            _ => {}
+        }
+
+        let rest = &self.source[self.peek_pos() + 1..];
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_len];
+        let after_name = rest[name_len..].trim_ascii_start();
+        match name {
+            "if" | "for" | "foreach" | "while" | "switch" | "lock" | "try" | "section" => {
+                self.parse_razor_block().map(NodeKind::RazorBlock)
+            }
+            "using" if after_name.starts_with('(') => {
+                self.parse_razor_block().map(NodeKind::RazorBlock)
+            }
+            "code" | "functions" if after_name.starts_with('{') => {
+                self.parse_razor_code().map(NodeKind::RazorCode)
+            }
+            _ if helpers::is_razor_directive_name(name) => {
+                self.parse_razor_directive().map(NodeKind::RazorDirective)
+            }
+            _ => self.parse_razor_expr().map(NodeKind::RazorExpr),
+        }
+    }
+
     pub fn parse_root(&mut self) -> PResult<Root<'s>> {
+        if self.language == Language::Mdx {
+            return self.parse_mdx_root();
+        }
+
         let mut children = vec![];
         while self.chars.peek().is_some() {
             children.push(self.parse_node()?);
@@ -1826,6 +2871,179 @@ impl<'s> Parser<'s> {
         Ok(Root { children })
     }
 
+    /// MDX document is parsed line by line.
+    /// JSX blocks, expressions and ESM statements are recognized only at the start of line
+    /// after a blank line, and all other lines are Markdown content.
+    fn parse_mdx_root(&mut self) -> PResult<Root<'s>> {
+        let mut children = vec![];
+        let mut markdown: Option<(usize, usize)> = None;
+        let mut fence: Option<&str> = None;
+        let mut after_blank_line = true;
+
+        fn flush_markdown<'s>(
+            source: &'s str,
+            markdown: &mut Option<(usize, usize)>,
+            children: &mut Vec<Node<'s>>,
+        ) {
+            if let Some((start, end)) = markdown.take() {
+                let raw = unsafe { source.get_unchecked(start..end) };
+                children.push(Node {
+                    kind: NodeKind::MdxMarkdown(MdxMarkdown { raw, start }),
+                    raw,
+                    start,
+                });
+            }
+        }
+
+        while self.chars.peek().is_some() {
+            let line_start = self.peek_pos();
+            let rest = unsafe { self.source.get_unchecked(line_start..) };
+            let line = rest.split_inclusive('\n').next().unwrap_or(rest);
+            let line_end = line_start + line.len();
+            let content = line.trim_end();
+
+            if content.is_empty() {
+                after_blank_line = fence.is_none();
+            } else if let Some(marker) = fence {
+                let trimmed = content.trim_start();
+                if trimmed.starts_with(marker)
+                    && trimmed.trim_start_matches(&marker[..1]).is_empty()
+                {
+                    fence = None;
+                }
+                markdown = markdown.map(|(start, _)| (start, line_start + content.len()));
+            } else {
+                if after_blank_line {
+                    if line_start == 0 && content == "---" {
+                        let (kind, raw, start) = self.with_taken(|parser| {
+                            parser.parse_front_matter().map(NodeKind::FrontMatter)
+                        })?;
+                        self.state.has_front_matter = true;
+                        children.push(Node { kind, raw, start });
+                        continue;
+                    }
+                    if content.starts_with("import ") || content.starts_with("export ") {
+                        flush_markdown(self.source, &mut markdown, &mut children);
+                        let end = rest
+                            .find("\n\n")
+                            .or_else(|| rest.find("\n\r\n"))
+                            .map_or(self.source.len(), |i| line_start + i);
+                        let raw = unsafe { self.source.get_unchecked(line_start..end) }.trim_end();
+                        while self.chars.next_if(|(i, _)| *i < end).is_some() {}
+                        children.push(Node {
+                            kind: NodeKind::MdxEsm(MdxEsm {
+                                raw,
+                                start: line_start,
+                            }),
+                            raw,
+                            start: line_start,
+                        });
+                        continue;
+                    }
+                    if (content.starts_with('{')
+                        || content.starts_with('<')
+                            && content[1..]
+                                .starts_with(|c: char| c.is_ascii_alphabetic() || c == '>'))
+                        && let Ok(mut nodes) = self.try_parse(Parser::parse_mdx_flow)
+                    {
+                        flush_markdown(self.source, &mut markdown, &mut children);
+                        children.append(&mut nodes);
+                        continue;
+                    }
+                }
+
+                let trimmed = content.trim_start();
+                fence = ["```", "~~~"]
+                    .into_iter()
+                    .find(|marker| trimmed.starts_with(marker))
+                    .map(|marker| {
+                        let len = trimmed.len() - trimmed.trim_start_matches(&marker[..1]).len();
+                        &trimmed[..len]
+                    });
+                markdown = Some((
+                    markdown.map_or(line_start, |(start, _)| start),
+                    line_start + content.len(),
+                ));
+                after_blank_line = false;
+            }
+            while self.chars.next_if(|(i, _)| *i < line_end).is_some() {}
+        }
+        flush_markdown(self.source, &mut markdown, &mut children);
+
+        Ok(Root { children })
+    }
+
+    /// Markdown lines inside JSX element in MDX, which start on their own line.
+    /// They end before a line that starts with JSX tag or expression, except in code fences.
+    /// Leading whitespace is returned as a separate text node.
+    fn parse_mdx_markdown_in_jsx(&mut self) -> Option<(TextNode<'s>, MdxMarkdown<'s>)> {
+        let ws_start = self.peek_pos();
+        let rest = unsafe { self.source.get_unchecked(ws_start..) };
+        let content = rest.trim_start();
+        let whitespace = &rest[..rest.len() - content.len()];
+        if !whitespace.contains('\n') || content.is_empty() || content.starts_with(['<', '{']) {
+            return None;
+        }
+
+        let start = ws_start + whitespace.len();
+        let mut end = start;
+        let mut fence: Option<&str> = None;
+        let mut line_start = start;
+        for line in content.split_inclusive('\n') {
+            let trimmed = line.trim();
+            if let Some(marker) = fence {
+                if trimmed.starts_with(marker)
+                    && trimmed.trim_start_matches(&marker[..1]).is_empty()
+                {
+                    fence = None;
+                }
+            } else if line_start > start && trimmed.starts_with(['<', '{']) {
+                break;
+            } else {
+                fence = ["```", "~~~"]
+                    .into_iter()
+                    .find(|marker| trimmed.starts_with(marker))
+                    .map(|marker| {
+                        let len = trimmed.len() - trimmed.trim_start_matches(&marker[..1]).len();
+                        &trimmed[..len]
+                    });
+            }
+            if !trimmed.is_empty() {
+                end = line_start + line.trim_end().len();
+            }
+            line_start += line.len();
+        }
+
+        while self.chars.next_if(|(i, _)| *i < end).is_some() {}
+        let raw = unsafe { self.source.get_unchecked(start..end) };
+        Some((
+            TextNode {
+                raw: whitespace,
+                line_breaks: whitespace.matches('\n').count(),
+                start: ws_start,
+            },
+            MdxMarkdown { raw, start },
+        ))
+    }
+
+    /// JSX elements or expressions which occupy whole lines in MDX.
+    fn parse_mdx_flow(&mut self) -> PResult<Vec<Node<'s>>> {
+        let mut nodes = vec![];
+        loop {
+            nodes.push(self.parse_node()?);
+            while self
+                .chars
+                .next_if(|(_, c)| *c == ' ' || *c == '\t')
+                .is_some()
+            {}
+            match self.chars.peek() {
+                Some((_, '<' | '{')) => {}
+                Some((_, '\r' | '\n')) | None => return Ok(nodes),
+                Some(..) => return Err(self.emit_error(SyntaxErrorKind::ExpectMdxFlow)),
+            }
+        }
+    }
+
     fn parse_svelte_at_tag(&mut self) -> PResult<SvelteAtTag<'s>> {
         if self.try_consume_str("{@").is_none() {
             return Err(self.emit_error(SyntaxErrorKind::ExpectSvelteAtTag));
@@ -1837,6 +3055,7 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_svelte_attachment(&mut self) -> PResult<SvelteAttachment<'s>> {
+        let start = self.peek_pos();
         if self
             .chars
             .next_if(|(_, c)| *c == '{')
@@ -1845,13 +3064,14 @@ impl<'s> Parser<'s> {
             .is_some()
         {
             self.parse_svelte_or_astro_expr()
-                .map(|expr| SvelteAttachment { expr })
+                .map(|expr| SvelteAttachment { expr, start })
         } else {
             Err(self.emit_error(SyntaxErrorKind::ExpectSvelteAttachment))
         }
     }
 
     fn parse_svelte_attr(&mut self) -> PResult<SvelteAttribute<'s>> {
+        let start = self.peek_pos();
         let name = if self.chars.next_if(|(_, c)| *c == '{').is_some() {
             None
         } else {
@@ -1871,7 +3091,7 @@ impl<'s> Parser<'s> {
         };
 
         self.parse_svelte_or_astro_expr()
-            .map(|expr| SvelteAttribute { name, expr })
+            .map(|expr| SvelteAttribute { name, expr, start })
     }
 
     fn parse_svelte_await_block(&mut self) -> PResult<Box<SvelteAwaitBlock<'s>>> {
@@ -2428,9 +3648,15 @@ impl<'s> Parser<'s> {
                 self.chars.next();
                 (start, start + c.len_utf8())
             }
-            Some((i, '{')) if matches!(self.language, Language::Jinja) => (*i, *i + 1),
-            Some((_, '>')) if matches!(self.language, Language::Astro) => {
-                // Astro allows fragment
+            Some((i, _))
+                if self.language.dialect().is_some_and(|dialect| {
+                    self.source[*i..].starts_with(dialect.interpolation().0)
+                }) =>
+            {
+                (*i, *i)
+            }
+            Some((_, '>')) if matches!(self.language, Language::Astro | Language::Mdx) => {
+                // Astro and MDX allow fragment
                 return Ok("");
             }
             _ => return Err(self.emit_error(SyntaxErrorKind::ExpectTagName)),
@@ -2440,15 +3666,14 @@ impl<'s> Parser<'s> {
             if is_html_tag_name_char(*c) {
                 end = *i + c.len_utf8();
                 self.chars.next();
-            } else if *c == '{' && matches!(self.language, Language::Jinja) {
-                let current_i = *i;
-                let mut chars = self.chars.clone();
-                chars.next();
-                if chars.next_if(|(_, c)| *c == '{').is_some() {
-                    end = current_i + self.parse_mustache_interpolation()?.0.len() + "{{}}".len();
-                } else {
-                    break;
-                }
+            } else if let Some(dialect) = self.language.dialect()
+                && matches!(
+                    self.peek_dialect_delimiter(dialect),
+                    Some(DialectDelimiter::Interpolation)
+                )
+            {
+                self.parse_dialect_interpolation(dialect)?;
+                end = self.peek_pos();
             } else {
                 break;
             }
@@ -2463,14 +3688,44 @@ impl<'s> Parser<'s> {
         };
 
         let mut line_breaks = if first_char == '\n' { 1 } else { 0 };
+        if first_char == '@' && matches!(self.language, Language::Blade) {
+            self.skip_blade_escaped_echo();
+        }
+        // braces of C# code inside Razor blocks, which are unrelated to block end
+        let mut razor_braces = 0u16;
+        match first_char {
+            '@' if matches!(self.language, Language::Razor) => {
+                self.chars.next_if(|(_, c)| *c == '@');
+            }
+            '{' if matches!(self.language, Language::Razor) => razor_braces += 1,
+            _ => {}
+        }
         let end;
         loop {
             match self.chars.peek() {
+                Some((i, _))
+                    if self.language.dialect().is_some_and(|dialect| {
+                        dialect::match_opening_delimiter(dialect, &self.source[*i..]).is_some()
+                    }) =>
+                {
+                    end = *i;
+                    break;
+                }
                 Some((i, '{')) => match self.language {
-                    Language::Html | Language::Xml => {
+                    Language::Html
+                    | Language::Xml
+                    | Language::Jinja
+                    | Language::Liquid
+                    | Language::Django
+                    | Language::GoTemplate
+                    | Language::Erb
+                    | Language::Ejs
+                    | Language::Php
+                    | Language::Heex
+                    | Language::Custom(..) => {
                         self.chars.next();
                     }
-                    Language::Vue | Language::Vento | Language::Mustache => {
+                    Language::Vue | Language::Vento | Language::Mustache | Language::Glimmer => {
                         let i = *i;
                         let mut chars = self.chars.clone();
                         chars.next();
@@ -2480,23 +3735,23 @@ impl<'s> Parser<'s> {
                         }
                         self.chars.next();
                     }
-                    Language::Svelte | Language::Astro => {
+                    Language::Svelte | Language::Astro | Language::Mdx => {
                         end = *i;
                         break;
                     }
-                    Language::Jinja => {
+                    Language::Blade => {
                         let i = *i;
-                        let mut chars = self.chars.clone();
-                        chars.next();
-                        if chars
-                            .next_if(|(_, c)| *c == '%' || *c == '{' || *c == '#')
-                            .is_some()
+                        if self.source[i..].starts_with("{{") || self.source[i..].starts_with("{!!")
                         {
                             end = i;
                             break;
                         }
                         self.chars.next();
                     }
+                    Language::Razor => {
+                        razor_braces += 1;
+                        self.chars.next();
+                    }
                     Language::Angular => {
                         let i = *i;
                         let mut chars = self.chars.clone();
@@ -2547,6 +3802,41 @@ impl<'s> Parser<'s> {
                     end = *i;
                     break;
                 }
+                Some((i, '\n'))
+                    if matches!(self.language, Language::Razor)
+                        && self.state.razor_code_context
+                        && razor_braces == 0 =>
+                {
+                    end = *i;
+                    break;
+                }
+                Some((i, '}')) if matches!(self.language, Language::Razor) => {
+                    if razor_braces == 0 {
+                        end = *i;
+                        break;
+                    }
+                    razor_braces -= 1;
+                    self.chars.next();
+                }
+                Some((i, '@')) if matches!(self.language, Language::Razor) => {
+                    if is_razor_transition_at(self.source, *i) {
+                        end = *i;
+                        break;
+                    }
+                    self.chars.next();
+                    // `@@` is escaped `@`
+                    self.chars.next_if(|(_, c)| *c == '@');
+                }
+                Some((i, '@')) if matches!(self.language, Language::Blade) => {
+                    if is_blade_directive_at(self.source, *i) {
+                        end = *i;
+                        break;
+                    }
+                    self.chars.next();
+                    // `@@` is escaped `@`
+                    self.chars.next_if(|(_, c)| *c == '@');
+                    self.skip_blade_escaped_echo();
+                }
                 Some((_, c)) => {
                     if *c == '\n' {
                         line_breaks += 1;
@@ -2690,7 +3980,7 @@ impl<'s> Parser<'s> {
                                     next_tag_start,
                                 )))
                             })
-                            .map(|(kind, raw)| Node { kind, raw })?;
+                            .map(|(kind, raw, start)| Node { kind, raw, start })?;
                         if let Some(VentoTagOrChildren::Children(nodes)) = body.last_mut() {
                             nodes.push(node);
                         } else {
@@ -2719,6 +4009,7 @@ impl<'s> Parser<'s> {
     }
 
     fn parse_vue_directive(&mut self) -> PResult<VueDirective<'s>> {
+        let start = self.peek_pos();
         let name = match self.chars.peek() {
             Some((_, ':')) => {
                 self.chars.next();
@@ -2772,6 +4063,7 @@ impl<'s> Parser<'s> {
             name,
             arg_and_modifiers,
             value,
+            start,
         })
     }
 
@@ -2820,12 +4112,22 @@ fn is_html_tag_name_char(c: char) -> bool {
 /// Checks whether a character is valid in an HTML tag name, for specific template languages.
 ///
 /// For example:
-/// - Astro allows '>' in tag names (for fragments)
+/// - Astro and MDX allow '>' in tag names (for fragments)
 /// - Jinja allows '{' for template expressions like <{{ tag_name }}>
 fn is_special_tag_name_char(c: char, language: Language) -> bool {
     match language {
-        Language::Astro => c == '>',
-        Language::Jinja => c == '{',
+        Language::Astro | Language::Mdx => c == '>',
+        Language::Jinja
+        | Language::Liquid
+        | Language::Django
+        | Language::GoTemplate
+        | Language::Erb
+        | Language::Ejs
+        | Language::Php
+        | Language::Heex
+        | Language::Custom(..) => language
+            .dialect()
+            .is_some_and(|dialect| dialect.interpolation().0.starts_with(c)),
         _ => false,
     }
 }
@@ -2834,12 +4136,44 @@ fn is_attr_name_char(c: char) -> bool {
     !matches!(c, '"' | '\'' | '>' | '/' | '=') && !c.is_ascii_whitespace()
 }
 
-fn parse_jinja_tag_name<'s>(tag: &JinjaTag<'s>) -> &'s str {
-    let trimmed = tag.content.trim_start_matches(['+', '-']).trim_start();
-    trimmed
-        .split_once(|c: char| !c.is_ascii_alphanumeric() && c != '_')
-        .map(|(name, _)| name)
-        .unwrap_or(trimmed)
+/// Checks if there's a Blade directive at the given position,
+/// not an email address or an escaped `@@`.
+fn is_blade_directive_at(source: &str, pos: usize) -> bool {
+    if source[..pos]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '@'))
+    {
+        return false;
+    }
+    let Some(rest) = source[pos..].strip_prefix('@') else {
+        return false;
+    };
+    let name_len = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    let (name, rest) = rest.split_at(name_len);
+    !name.is_empty()
+        && (helpers::is_blade_directive_name(name)
+            || helpers::is_blade_directive_with_args(name)
+                && rest.trim_start_matches([' ', '\t']).starts_with('('))
+}
+
+/// Checks if there's a Razor transition `@` at the given position,
+/// which is not escaped by `@@` and not a part of email address.
+fn is_razor_transition_at(source: &str, pos: usize) -> bool {
+    if source[..pos]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || c == '@')
+    {
+        return false;
+    }
+    let mut chars = source[pos..].chars();
+    chars.next() == Some('@')
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '_' | '{' | '(' | '*'))
 }
 
 fn is_vento_interpolation(tag_name: &str) -> bool {
@@ -2857,7 +4191,7 @@ fn is_vento_interpolation(tag_name: &str) -> bool {
     )
 }
 
-fn strip_hbs_whitespace_control(text: &str) -> (&str, bool, bool) {
+pub(crate) fn strip_hbs_whitespace_control(text: &str) -> (&str, bool, bool) {
     let (text, before) = if let Some(stripped) = text.strip_prefix('~') {
         (stripped, true)
     } else {
@@ -2877,9 +4211,10 @@ type AngularIfCond<'s> = ((&'s str, usize), Option<(&'s str, usize)>);
 trait HasJinjaFlowControl<'s>: Sized {
     type Intermediate;
 
-    fn build(intermediate: Self::Intermediate, raw: &'s str) -> Self;
+    fn build(intermediate: Self::Intermediate, raw: &'s str, start: usize) -> Self;
     fn from_tag(tag: JinjaTag<'s>) -> Self::Intermediate;
     fn from_block(block: JinjaBlock<'s, Self>) -> Self::Intermediate;
+    fn build_raw_text(text: TextNode<'s>) -> Option<Self>;
 
     fn skip_ws_before_jinja_block_end() -> bool;
 }
@@ -2887,10 +4222,11 @@ trait HasJinjaFlowControl<'s>: Sized {
 impl<'s> HasJinjaFlowControl<'s> for Node<'s> {
     type Intermediate = NodeKind<'s>;
 
-    fn build(intermediate: Self::Intermediate, raw: &'s str) -> Self {
+    fn build(intermediate: Self::Intermediate, raw: &'s str, start: usize) -> Self {
         Node {
             kind: intermediate,
             raw,
+            start,
         }
     }
 
@@ -2902,6 +4238,14 @@ impl<'s> HasJinjaFlowControl<'s> for Node<'s> {
         NodeKind::JinjaBlock(block)
     }
 
+    fn build_raw_text(text: TextNode<'s>) -> Option<Self> {
+        Some(Node {
+            raw: text.raw,
+            start: text.start,
+            kind: NodeKind::Text(text),
+        })
+    }
+
     fn skip_ws_before_jinja_block_end() -> bool {
         false
     }
@@ -2910,7 +4254,7 @@ impl<'s> HasJinjaFlowControl<'s> for Node<'s> {
 impl<'s> HasJinjaFlowControl<'s> for Attribute<'s> {
     type Intermediate = Attribute<'s>;
 
-    fn build(intermediate: Self::Intermediate, _: &'s str) -> Self {
+    fn build(intermediate: Self::Intermediate, _: &'s str, _: usize) -> Self {
         intermediate
     }
 
@@ -2922,6 +4266,10 @@ impl<'s> HasJinjaFlowControl<'s> for Attribute<'s> {
         Attribute::JinjaBlock(block)
     }
 
+    fn build_raw_text(_: TextNode<'s>) -> Option<Self> {
+        None
+    }
+
     fn skip_ws_before_jinja_block_end() -> bool {
         true
     }
@@ -2933,6 +4281,13 @@ pub fn parse_as_interpolated(
     language: Language,
     attr: bool,
 ) -> (Vec<&'_ str>, Vec<(&'_ str, usize)>) {
+    if let Some(dialect) = language.dialect() {
+        return parse_as_dialect_interpolated(text, base_start, dialect);
+    }
+    if language == Language::Vue && !attr {
+        return parse_as_vue_style_interpolated(text, base_start);
+    }
+
     let mut statics = Vec::with_capacity(1);
     let mut dynamics = Vec::new();
     let mut chars = text.char_indices().peekable();
@@ -2951,7 +4306,7 @@ pub fn parse_as_interpolated(
                         pos = i;
                         brace_stack += 1;
                     }
-                    Language::Jinja | Language::Vento | Language::Mustache
+                    Language::Vento | Language::Mustache | Language::Glimmer
                         if chars.next_if(|(_, c)| *c == '{').is_some() =>
                     {
                         statics.push(unsafe { text.get_unchecked(pos..i) });
@@ -2975,7 +4330,7 @@ pub fn parse_as_interpolated(
                         pos = i + 1;
                         brace_stack = 0;
                     }
-                    Language::Jinja | Language::Vento | Language::Mustache
+                    Language::Vento | Language::Mustache | Language::Glimmer
                         if chars.next_if(|(_, c)| *c == '}').is_some() =>
                     {
                         dynamics.push((
@@ -2994,3 +4349,115 @@ pub fn parse_as_interpolated(
     statics.push(unsafe { text.get_unchecked(pos..) });
     (statics, dynamics)
 }
+
+/// Arguments of `v-bind()` in `<style>` of Vue SFC are JavaScript expressions,
+/// while `v-bind(` and `)` are kept in statics.
+fn parse_as_vue_style_interpolated(
+    text: &'_ str,
+    base_start: usize,
+) -> (Vec<&'_ str>, Vec<(&'_ str, usize)>) {
+    const V_BIND: &str = "v-bind(";
+    let mut statics = Vec::with_capacity(1);
+    let mut dynamics = Vec::new();
+    let mut pos = 0;
+    let mut skip_until = 0;
+    for (i, c) in text.char_indices() {
+        if i < skip_until {
+            continue;
+        }
+        match c {
+            // `v-bind()` in comments and strings isn't interpolation
+            '/' if text[i + 1..].starts_with('*') => {
+                skip_until = text[i + 2..]
+                    .find("*/")
+                    .map_or(text.len(), |end| i + 2 + end + 2);
+            }
+            '\'' | '"' => {
+                let mut escaped = false;
+                skip_until = text[i + 1..]
+                    .char_indices()
+                    .find_map(|(j, ch)| {
+                        if escaped {
+                            escaped = false;
+                        } else if ch == '\\' {
+                            escaped = true;
+                        } else if ch == c || ch == '\n' {
+                            return Some(i + 1 + j + ch.len_utf8());
+                        }
+                        None
+                    })
+                    .unwrap_or(text.len());
+            }
+            'v' if text[i..].starts_with(V_BIND)
+                && !text[..i]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+            {
+                let expr_start = i + V_BIND.len();
+                let mut depth = 0u8;
+                let mut quote = None;
+                let Some(expr_end) = text[expr_start..].char_indices().find_map(|(i, c)| {
+                    match (c, quote) {
+                        (c, Some(q)) if c == q => quote = None,
+                        (_, Some(..)) => {}
+                        ('\'' | '"' | '`', None) => quote = Some(c),
+                        ('(' | '[' | '{', None) => depth = depth.saturating_add(1),
+                        (')', None) if depth == 0 => return Some(expr_start + i),
+                        (')' | ']' | '}', None) => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+                    None
+                }) else {
+                    break;
+                };
+                statics.push(unsafe { text.get_unchecked(pos..expr_start) });
+                dynamics.push((
+                    unsafe { text.get_unchecked(expr_start..expr_end) },
+                    base_start + expr_start,
+                ));
+                pos = expr_end;
+                skip_until = expr_end;
+            }
+            _ => {}
+        }
+    }
+    statics.push(unsafe { text.get_unchecked(pos..) });
+    (statics, dynamics)
+}
+
+fn parse_as_dialect_interpolated<'a>(
+    text: &'a str,
+    base_start: usize,
+    dialect: &dyn Dialect,
+) -> (Vec<&'a str>, Vec<(&'a str, usize)>) {
+    let (open, close) = dialect.interpolation();
+    let mut statics = Vec::with_capacity(1);
+    let mut dynamics = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find(open) {
+        let expr_start = pos + offset + open.len();
+        let mut depth = 0u8;
+        let Some(expr_end) = text[expr_start..].char_indices().find_map(|(i, c)| {
+            match c {
+                '{' | '[' | '(' => depth = depth.saturating_add(1),
+                '}' | ']' | ')' if depth > 0 => depth -= 1,
+                _ if depth == 0 && text[expr_start + i..].starts_with(close) => {
+                    return Some(expr_start + i);
+                }
+                _ => {}
+            }
+            None
+        }) else {
+            break;
+        };
+        statics.push(unsafe { text.get_unchecked(pos..pos + offset) });
+        dynamics.push((
+            unsafe { text.get_unchecked(expr_start..expr_end) },
+            base_start + expr_start,
+        ));
+        pos = expr_end + close.len();
+    }
+    statics.push(unsafe { text.get_unchecked(pos..) });
+    (statics, dynamics)
+}
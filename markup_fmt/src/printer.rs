@@ -3,8 +3,8 @@ use crate::{
     ast::*,
     config::{Quotes, ScriptFormatter, VSlotStyle, VueComponentCase, WhitespaceSensitivity},
     ctx::{Ctx, Hints},
-    helpers,
-    parser::parse_as_interpolated,
+    dialect, helpers,
+    parser::{parse_as_interpolated, strip_hbs_whitespace_control},
     state::State,
 };
 use anyhow::Error;
@@ -375,6 +375,9 @@ impl<'s> DocGen<'s> for Attribute<'s> {
             Attribute::SvelteAttachment(svelte_attachment) => svelte_attachment.doc(ctx, state),
             Attribute::VueDirective(vue_directive) => vue_directive.doc(ctx, state),
             Attribute::Astro(astro_attribute) => astro_attribute.doc(ctx, state),
+            Attribute::BladeDirective(blade_directive) => blade_directive.doc(ctx, state),
+            Attribute::BladeEcho(blade_echo) => blade_echo.doc(ctx, state),
+            Attribute::Heex(heex_attribute) => heex_attribute.doc(ctx, state),
             Attribute::JinjaBlock(jinja_block) => jinja_block.doc(ctx, state),
             Attribute::JinjaComment(jinja_comment) => jinja_comment.doc(ctx, state),
             Attribute::JinjaTag(jinja_tag) => jinja_tag.doc(ctx, state),
@@ -384,6 +387,144 @@ impl<'s> DocGen<'s> for Attribute<'s> {
     }
 }
 
+impl<'s> DocGen<'s> for BladeBlock<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        if let [first, last] = &self.directives[..]
+            && let [children] = &self.children[..]
+            && let [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] = &children[..]
+        {
+            if first.name == "php" {
+                let state = State {
+                    indent_level: state.indent_level + 1,
+                    ..state.clone()
+                };
+                let formatted = ctx.format_php_block(text_node.raw, text_node.start, &state);
+                let mut docs = Vec::with_capacity(4);
+                docs.push(first.doc(ctx, &state));
+                if !formatted.is_empty() {
+                    docs.push(
+                        Doc::hard_line()
+                            .concat(reflow_with_indent(&formatted, true))
+                            .nest(ctx.indent_width),
+                    );
+                    docs.push(Doc::hard_line());
+                }
+                docs.push(last.doc(ctx, &state));
+                return Doc::list(docs);
+            } else if first.name == "verbatim" {
+                return first
+                    .doc(ctx, state)
+                    .concat(reflow_raw(text_node.raw))
+                    .append(last.doc(ctx, state));
+            }
+        }
+
+        let directives = self
+            .directives
+            .iter()
+            .map(|directive| directive.doc(ctx, state))
+            .collect::<Vec<_>>();
+        Doc::list(
+            directives
+                .into_iter()
+                .interleave(
+                    self.children
+                        .iter()
+                        .map(|nodes| format_control_structure_block_children(nodes, ctx, state)),
+                )
+                .collect(),
+        )
+    }
+}
+
+impl<'s> DocGen<'s> for BladeComment<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, _: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        if ctx.options.format_comments {
+            Doc::text("{{--")
+                .append(Doc::line_or_space())
+                .concat(reflow_with_indent(self.raw.trim(), true))
+                .nest(ctx.indent_width)
+                .append(Doc::line_or_space())
+                .append(Doc::text("--}}"))
+                .group()
+        } else {
+            Doc::text("{{--")
+                .concat(reflow_raw(self.raw))
+                .append(Doc::text("--}}"))
+        }
+    }
+}
+
+impl<'s> DocGen<'s> for BladeDirective<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let directive = Doc::char('@').append(Doc::text(self.name));
+        let Some((args, start)) = self.args else {
+            return directive;
+        };
+        // control structures are separated from their conditions by a space,
+        // just like PHP statements
+        let (formatted, is_control) = match self.name {
+            "if" | "elseif" | "while" | "for" => (
+                ctx.format_php_stmt_header(
+                    if self.name == "elseif" {
+                        "if"
+                    } else {
+                        self.name
+                    },
+                    args,
+                    start,
+                ),
+                true,
+            ),
+            "foreach" | "forelse" => (ctx.format_php_stmt_header("foreach", args, start), true),
+            "unless" => (ctx.format_php_expr(args, start, state), true),
+            _ => (ctx.format_php_expr(args, start, state), false),
+        };
+        directive
+            .append(if is_control { Doc::space() } else { Doc::nil() })
+            .append(Doc::char('('))
+            .concat(reflow_with_indent(&formatted, true))
+            .append(Doc::char(')'))
+    }
+}
+
+impl<'s> DocGen<'s> for BladeEcho<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let (open, close) = if self.escaped {
+            ("{{", "}}")
+        } else {
+            ("{!!", "!!}")
+        };
+        Doc::text(open)
+            .append(Doc::line_or_space())
+            .concat(reflow_with_indent(
+                &ctx.format_php_expr(self.expr, self.start, state),
+                true,
+            ))
+            .nest(ctx.indent_width)
+            .append(Doc::line_or_space())
+            .append(Doc::text(close))
+            .group()
+    }
+}
+
 impl<'s> DocGen<'s> for Cdata<'s> {
     fn doc<F>(&self, _: &mut Ctx<'s, F>, _: &State<'s>) -> Doc<'s>
     where
@@ -450,7 +591,21 @@ impl<'s> DocGen<'s> for Element<'s> {
             .and_then(|(namespace, name)| namespace.eq_ignore_ascii_case("html").then_some(name))
             .unwrap_or(self.tag_name);
         let formatted_tag_name = match ctx.language {
-            Language::Html | Language::Jinja | Language::Vento | Language::Mustache
+            Language::Html
+            | Language::Jinja
+            | Language::Vento
+            | Language::Mustache
+            | Language::Glimmer
+            | Language::Liquid
+            | Language::Django
+            | Language::GoTemplate
+            | Language::Erb
+            | Language::Ejs
+            | Language::Php
+            | Language::Heex
+            | Language::Blade
+            | Language::Razor
+            | Language::Custom(..)
                 if css_dataset::tags::STANDARD_HTML_TAGS
                     .iter()
                     .any(|tag| tag.eq_ignore_ascii_case(self.tag_name)) =>
@@ -513,7 +668,12 @@ impl<'s> DocGen<'s> for Element<'s> {
         docs.push(Doc::char('<'));
         docs.push(Doc::text(formatted_tag_name.clone()));
 
-        match &*self.attrs {
+        let attrs = if ctx.language == Language::Vue && ctx.options.vue_attribute_order {
+            helpers::sort_vue_attrs(&self.attrs)
+        } else {
+            self.attrs.iter().collect()
+        };
+        match &*attrs {
             [] => {
                 if self_closing && (self.void_element || is_empty) {
                     docs.push(Doc::text(" />"));
@@ -549,7 +709,7 @@ impl<'s> DocGen<'s> for Element<'s> {
             _ => {
                 let attrs_sep = if self.first_attr_same_line {
                     Doc::line_or_space()
-                } else if self.attrs.len() <= 1 {
+                } else if attrs.len() <= 1 {
                     if ctx.options.single_attr_same_line {
                         Doc::line_or_space()
                     } else {
@@ -568,7 +728,7 @@ impl<'s> DocGen<'s> for Element<'s> {
                 let attrs = if let Some(max) = ctx.options.max_attrs_per_line {
                     Doc::line_or_space()
                         .concat(itertools::intersperse(
-                            self.attrs.chunks(max.into()).map(|chunk| {
+                            attrs.chunks(max.into()).map(|chunk| {
                                 Doc::list(
                                     itertools::intersperse(
                                         chunk.iter().map(|attr| attr.doc(ctx, &state)),
@@ -583,7 +743,7 @@ impl<'s> DocGen<'s> for Element<'s> {
                         .nest(ctx.indent_width)
                 } else {
                     Doc::list(
-                        self.attrs
+                        attrs
                             .iter()
                             .flat_map(|attr| [attrs_sep.clone(), attr.doc(ctx, &state)].into_iter())
                             .collect(),
@@ -681,66 +841,28 @@ impl<'s> DocGen<'s> for Element<'s> {
                 if text_node.raw.chars().all(|c| c.is_ascii_whitespace()) {
                     docs.push(Doc::hard_line());
                 } else {
-                    let type_attr = self.attrs.iter().find_map(|attr| match attr {
-                        Attribute::Native(native) if native.name.eq_ignore_ascii_case("type") => {
-                            native.value.map(|(value, _)| value.to_ascii_lowercase())
-                        }
-                        _ => None,
-                    });
                     let is_script_indent = ctx.script_indent();
                     if is_script_indent {
                         state.indent_level += 1;
                     }
-                    match type_attr.as_deref() {
-                        Some(
-                            "module"
-                            | "application/javascript"
-                            | "text/javascript"
-                            | "application/ecmascript"
-                            | "text/ecmascript"
-                            | "application/x-javascript"
-                            | "application/x-ecmascript"
-                            | "text/x-javascript"
-                            | "text/x-ecmascript"
-                            | "text/jsx"
-                            | "text/babel",
-                        )
-                        | None => {
-                            let lang = self
-                                .attrs
-                                .iter()
-                                .find_map(|attr| match attr {
-                                    Attribute::Native(native)
-                                        if native.name.eq_ignore_ascii_case("lang") =>
-                                    {
-                                        native.value.map(|(value, _)| value)
-                                    }
-                                    _ => None,
-                                })
-                                .unwrap_or(if matches!(ctx.language, Language::Astro) {
-                                    "ts"
-                                } else {
-                                    "js"
-                                });
-                            let lang = if self.attrs.iter().any(|attr| match attr {
-                                Attribute::Native(native)
-                                    if native.name.eq_ignore_ascii_case("type") =>
-                                {
-                                    native.value.is_some_and(|(value, _)| value == "module")
-                                }
-                                _ => false,
-                            }) {
-                                match lang {
-                                    "ts" => "mts",
-                                    "js" => "mjs",
-                                    lang => lang,
-                                }
-                            } else {
-                                lang
-                            };
+                    match helpers::script_kind(&self.attrs, ctx.language) {
+                        helpers::ScriptKind::Js(lang) => {
                             let formatted = if matches!(
                                 ctx.language,
-                                Language::Jinja | Language::Mustache | Language::Vento
+                                Language::Jinja
+                                    | Language::Mustache
+                                    | Language::Glimmer
+                                    | Language::Vento
+                                    | Language::Liquid
+                                    | Language::Django
+                                    | Language::GoTemplate
+                                    | Language::Erb
+                                    | Language::Ejs
+                                    | Language::Php
+                                    | Language::Heex
+                                    | Language::Blade
+                                    | Language::Razor
+                                    | Language::Custom(..)
                             ) {
                                 ctx.try_format_script(text_node.raw, lang, text_node.start, &state)
                                     .unwrap_or_else(|_| Cow::from(text_node.raw))
@@ -761,13 +883,7 @@ impl<'s> DocGen<'s> for Element<'s> {
                                 docs.push(doc);
                             }
                         }
-                        Some(
-                            "importmap"
-                            | "application/json"
-                            | "text/json"
-                            | "application/ld+json"
-                            | "speculationrules",
-                        ) => {
+                        helpers::ScriptKind::Json => {
                             let formatted = ctx.format_json(text_node.raw, text_node.start, &state);
                             let doc =
                                 Doc::hard_line().concat(reflow_with_indent(formatted.trim(), true));
@@ -777,7 +893,7 @@ impl<'s> DocGen<'s> for Element<'s> {
                                 docs.push(doc);
                             }
                         }
-                        Some(..) => {
+                        helpers::ScriptKind::Other => {
                             docs.extend(reflow_raw(text_node.raw.trim_ascii_end()));
                         }
                     }
@@ -795,18 +911,7 @@ impl<'s> DocGen<'s> for Element<'s> {
                 if text_node.raw.chars().all(|c| c.is_ascii_whitespace()) {
                     docs.push(Doc::hard_line());
                 } else {
-                    let lang = self
-                        .attrs
-                        .iter()
-                        .find_map(|attr| match attr {
-                            Attribute::Native(native_attribute)
-                                if native_attribute.name.eq_ignore_ascii_case("lang") =>
-                            {
-                                native_attribute.value.map(|(value, _)| value)
-                            }
-                            _ => None,
-                        })
-                        .unwrap_or("css");
+                    let lang = helpers::style_lang(&self.attrs);
                     let (statics, dynamics) =
                         parse_as_interpolated(text_node.raw, text_node.start, ctx.language, false);
                     const PLACEHOLDER: &str = "_saya0909_";
@@ -817,15 +922,29 @@ impl<'s> DocGen<'s> for Element<'s> {
                             .split(PLACEHOLDER)
                             .map(Cow::from)
                             .interleave(dynamics.iter().map(|(expr, start)| match ctx.language {
-                                Language::Jinja => Cow::from(format!(
-                                    "{{{{ {} }}}}",
-                                    ctx.format_jinja(expr, *start, true, &state),
-                                )),
+                                Language::Jinja
+                                | Language::Liquid
+                                | Language::Django
+                                | Language::GoTemplate
+                                | Language::Erb
+                                | Language::Ejs
+                                | Language::Php
+                                | Language::Heex
+                                | Language::Custom(..) => {
+                                    let (open, close) = ctx.jinja_dialect().interpolation();
+                                    Cow::from(format!(
+                                        "{open} {} {close}",
+                                        ctx.format_jinja(expr, *start, true, &state),
+                                    ))
+                                }
                                 Language::Vento => Cow::from(format!(
                                     "{{{{ {} }}}}",
                                     ctx.format_expr(expr, false, *start),
                                 )),
-                                Language::Mustache => Cow::from(format!("{{{{{expr}}}}}")),
+                                Language::Mustache | Language::Glimmer => {
+                                    Cow::from(format!("{{{{{expr}}}}}"))
+                                }
+                                Language::Vue => Cow::from(ctx.format_expr(expr, false, *start)),
                                 _ => unreachable!(),
                             }))
                             .collect::<String>()
@@ -842,6 +961,42 @@ impl<'s> DocGen<'s> for Element<'s> {
                     );
                 }
             }
+        } else if matches!(ctx.language, Language::Vue | Language::Svelte)
+            && is_root
+            && !is_empty
+            && helpers::is_non_html_template(tag_name, &self.attrs)
+        {
+            if let [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] = &self.children[..]
+            {
+                let lang = helpers::lang_attr(&self.attrs);
+                docs.push(format_raw_block_content(text_node, lang, ctx, &state));
+            }
+        } else if ctx.language == Language::Vue
+            && is_root
+            && !is_empty
+            && helpers::is_vue_custom_block(tag_name)
+        {
+            if let [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] = &self.children[..]
+            {
+                let lang = helpers::lang_attr(&self.attrs).or_else(|| {
+                    ctx.options
+                        .vue_custom_block_lang
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(tag_name))
+                        .map(|(_, lang)| lang.as_str())
+                });
+                docs.push(format_raw_block_content(text_node, lang, ctx, &state));
+            }
         } else if tag_name.eq_ignore_ascii_case("pre") || tag_name.eq_ignore_ascii_case("textarea")
         {
             if let [
@@ -935,6 +1090,25 @@ impl<'s> DocGen<'s> for FrontMatter<'s> {
     }
 }
 
+impl<'s> DocGen<'s> for HeexAttribute<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let expr = Doc::char('{')
+            .concat(reflow_with_indent(
+                &ctx.format_jinja(self.expr.0, self.expr.1, true, state),
+                true,
+            ))
+            .append(Doc::char('}'));
+        if let Some(name) = self.name {
+            Doc::text(name).append(Doc::char('=')).append(expr)
+        } else {
+            expr
+        }
+    }
+}
+
 impl<'s> DocGen<'s> for JinjaBlock<'s, Attribute<'s>> {
     fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
     where
@@ -964,6 +1138,56 @@ impl<'s> DocGen<'s> for JinjaBlock<'s, Node<'s>> {
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
+        if let [
+            JinjaTagOrChildren::Tag(first_tag),
+            JinjaTagOrChildren::Children(children),
+            JinjaTagOrChildren::Tag(last_tag),
+        ] = &*self.body
+            && let [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] = &**children
+        {
+            let dialect = ctx.jinja_dialect();
+            let tag_name = dialect.tag_name(first_tag.content);
+            if let Some(ext) = dialect.embedded_code(tag_name) {
+                let state = State {
+                    indent_level: state.indent_level + 1,
+                    ..state.clone()
+                };
+                let formatted = match ext {
+                    "json" => ctx.format_json(text_node.raw, text_node.start, &state),
+                    "css" | "scss" | "sass" | "less" => {
+                        ctx.format_style(text_node.raw, ext, text_node.start, &state)
+                    }
+                    _ => ctx.format_script(text_node.raw, ext, text_node.start, &state),
+                };
+                let formatted = formatted.trim();
+                let mut docs = Vec::with_capacity(4);
+                docs.push(first_tag.doc(ctx, &state));
+                if !formatted.is_empty() {
+                    docs.push(
+                        Doc::hard_line()
+                            .concat(reflow_with_indent(formatted, true))
+                            .nest(ctx.indent_width),
+                    );
+                    docs.push(Doc::hard_line());
+                }
+                docs.push(last_tag.doc(ctx, &state));
+                return Doc::list(docs);
+            } else if dialect.is_raw_block(tag_name)
+                // PHP block which contains unbalanced elements
+                || ctx.language == Language::Php && text_node.raw.contains('<')
+            {
+                return first_tag
+                    .doc(ctx, state)
+                    .concat(reflow_raw(text_node.raw))
+                    .append(last_tag.doc(ctx, state));
+            }
+        }
+
         Doc::list(
             self.body
                 .iter()
@@ -983,18 +1207,19 @@ impl<'s> DocGen<'s> for JinjaComment<'s> {
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
+        let (open, close) = ctx.jinja_dialect().comment();
         if ctx.options.format_comments {
-            Doc::text("{#")
+            Doc::text(open)
                 .append(Doc::line_or_space())
                 .concat(reflow_with_indent(self.raw.trim(), true))
                 .nest(ctx.indent_width)
                 .append(Doc::line_or_space())
-                .append(Doc::text("#}"))
+                .append(Doc::text(close))
                 .group()
         } else {
-            Doc::text("{#")
+            Doc::text(open)
                 .concat(reflow_raw(self.raw))
-                .append(Doc::text("#}"))
+                .append(Doc::text(close))
         }
     }
 }
@@ -1004,7 +1229,8 @@ impl<'s> DocGen<'s> for JinjaInterpolation<'s> {
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
-        Doc::text("{{")
+        let (open, close) = ctx.jinja_dialect().interpolation();
+        Doc::text(open)
             .append(if self.trim_prev {
                 Doc::char('-')
             } else {
@@ -1022,7 +1248,7 @@ impl<'s> DocGen<'s> for JinjaInterpolation<'s> {
             } else {
                 Doc::nil()
             })
-            .append(Doc::text("}}"))
+            .append(Doc::text(close))
             .group()
     }
 }
@@ -1032,27 +1258,90 @@ impl<'s> DocGen<'s> for JinjaTag<'s> {
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
-        let (prefix, content) = if let Some(content) = self.content.strip_prefix('-') {
-            ("-", content)
-        } else if let Some(content) = self.content.strip_prefix('+') {
-            ("+", content)
+        let dialect = ctx.jinja_dialect();
+        let markers = dialect.trim_markers();
+        // trim markers in Go template must be separated from the action by spaces,
+        // otherwise they're part of negative numbers
+        let is_go = ctx.language == Language::GoTemplate;
+        let output_marker = dialect::output_marker(dialect, self.content);
+        // tags which output values are expressions unless they open blocks
+        let is_expr = output_marker.is_some()
+            && !dialect.is_block_start(dialect.tag_name(self.content), self.content);
+        let (prefix, content) = if let Some(marker) = output_marker {
+            (marker, &self.content[marker.len()..])
         } else {
-            ("", self.content)
+            match self.content.strip_prefix(markers) {
+                Some(content)
+                    if !is_go || content.starts_with(|c: char| c.is_ascii_whitespace()) =>
+                {
+                    (&self.content[..1], content)
+                }
+                _ => ("", self.content),
+            }
         };
-        let (content, suffix) = if let Some(content) = content.strip_suffix('-') {
-            (content, "-")
-        } else if let Some(content) = content.strip_suffix('+') {
-            (content, "+")
-        } else {
-            (content, "")
+        let (content, suffix) = match content.strip_suffix(markers) {
+            Some(rest) if !is_go || rest.ends_with(|c: char| c.is_ascii_whitespace()) => {
+                (rest, &content[rest.len()..])
+            }
+            _ => (content, ""),
         };
 
+        let (open, close) = dialect.tag();
+        if is_go && let Some(comment) = helpers::go_template_comment(self.content) {
+            // trim markers must be separated from comment by spaces
+            return Doc::text(open)
+                .append(Doc::text(if prefix.is_empty() { "" } else { "- " }))
+                .append(Doc::text("/*"))
+                .concat(reflow_raw(comment))
+                .append(Doc::text("*/"))
+                .append(Doc::text(if suffix.is_empty() { "" } else { " -" }))
+                .append(Doc::text(close));
+        }
+        if ctx.language == Language::Php
+            && !ctx
+                .source
+                .get(self.start + self.content.len()..)
+                .is_some_and(|rest| rest.starts_with(close))
+        {
+            // closing tag at the end of PHP file is optional, and the code is kept as is
+            return Doc::text(open).concat(reflow_raw(self.content.trim_end()));
+        }
+        if ctx.language == Language::Liquid
+            && let Some(statements) = content.trim_start().strip_prefix("liquid")
+            && statements.contains('\n')
+            && statements.starts_with(|c: char| c.is_ascii_whitespace())
+        {
+            // statements in `liquid` tag are separated by line breaks
+            let statements = statements.trim_start_matches([' ', '\t']);
+            let statements = statements
+                .strip_prefix("\r\n")
+                .or_else(|| statements.strip_prefix('\n'))
+                .unwrap_or(statements)
+                .trim_end();
+            return Doc::text(open)
+                .append(Doc::text(prefix))
+                .append(Doc::text(" liquid"))
+                .append(
+                    Doc::hard_line()
+                        .concat(reflow_with_indent(statements, true))
+                        .nest(ctx.indent_width),
+                )
+                .append(Doc::hard_line())
+                .append(Doc::text(suffix))
+                .append(Doc::text(close));
+        }
+
         let mut docs = Vec::with_capacity(5);
-        docs.push(Doc::text("{%"));
+        docs.push(Doc::text(open));
         docs.push(Doc::text(prefix));
         docs.push(Doc::line_or_space());
+        let content = if is_go {
+            helpers::format_go_template_action(content)
+        } else {
+            Cow::from(content)
+        };
         docs.extend(reflow_with_indent(
-            ctx.format_jinja(content, self.start + prefix.len(), false, state)
+            ctx.format_jinja(&content, self.start + prefix.len(), is_expr, state)
                 .trim(),
             true,
         ));
@@ -1060,7 +1349,7 @@ impl<'s> DocGen<'s> for JinjaTag<'s> {
             .nest(ctx.indent_width)
             .append(Doc::line_or_space())
             .append(Doc::text(suffix))
-            .append(Doc::text("%}"))
+            .append(Doc::text(close))
             .group()
     }
 }
@@ -1080,11 +1369,78 @@ impl<'s> DocGen<'s> for JsComment<'s> {
     }
 }
 
+impl<'s> DocGen<'s> for MdxEsm<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let formatted = ctx.format_script(self.raw, "tsx", self.start, state);
+        Doc::list(reflow_with_indent(formatted.trim(), true).collect())
+    }
+}
+
+impl<'s> DocGen<'s> for MdxMarkdown<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        // Markdown inside JSX element is indented as the first line
+        let indent = ctx.source[..self.start]
+            .rsplit('\n')
+            .next()
+            .filter(|s| s.trim_ascii().is_empty())
+            .map_or(0, str::len);
+        let dedented;
+        let code = if indent > 0 {
+            dedented = self
+                .raw
+                .split('\n')
+                .enumerate()
+                .map(|(i, line)| {
+                    if i == 0 {
+                        line
+                    } else {
+                        let ws = line.len() - line.trim_ascii_start().len();
+                        &line[ws.min(indent)..]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            &dedented
+        } else {
+            self.raw
+        };
+        let formatted = ctx.format_markdown(code, self.start, state);
+        Doc::list(
+            formatted
+                .trim_end()
+                .split('\n')
+                .enumerate()
+                .flat_map(|(i, line)| {
+                    let line = line.strip_suffix('\r').unwrap_or(line);
+                    [
+                        if i == 0 {
+                            Doc::nil()
+                        } else if line.is_empty() {
+                            Doc::empty_line()
+                        } else {
+                            Doc::hard_line()
+                        },
+                        Doc::text(line.to_owned()),
+                    ]
+                })
+                .collect(),
+        )
+    }
+}
+
 impl<'s> DocGen<'s> for MustacheBlock<'s> {
     fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
+        let is_glimmer = matches!(ctx.language, Language::Glimmer);
+        let indent_width = ctx.indent_width;
         Doc::list(
             self.controls
                 .iter()
@@ -1096,7 +1452,20 @@ impl<'s> DocGen<'s> for MustacheBlock<'s> {
                     }
                     docs.push(Doc::text(control.prefix));
                     docs.push(Doc::text(control.name));
-                    if let Some(content) = control.content {
+                    if is_glimmer {
+                        if let Some(content) = control.content {
+                            let content = helpers::format_glimmer_expr(content);
+                            let mut params = helpers::split_glimmer_args(&content).into_iter();
+                            // keep `{{else if cond}}` together
+                            if control.name == "else"
+                                && let Some(keyword) = params.next()
+                            {
+                                docs.push(Doc::text(format!(" {keyword}")));
+                            }
+                            docs.push(glimmer_params_doc(params, indent_width));
+                            docs.push(Doc::line_or_nil());
+                        }
+                    } else if let Some(content) = control.content {
                         docs.push(Doc::space());
                         docs.extend(reflow_raw(content.trim_ascii()));
                     }
@@ -1104,7 +1473,7 @@ impl<'s> DocGen<'s> for MustacheBlock<'s> {
                         docs.push(Doc::char('~'));
                     }
                     docs.push(Doc::text("}}"));
-                    Doc::list(docs)
+                    Doc::list(docs).group()
                 })
                 .interleave(
                     self.children
@@ -1125,6 +1494,25 @@ impl<'s> DocGen<'s> for MustacheInterpolation<'s> {
             Doc::text("{{")
                 .concat(reflow_raw(self.content))
                 .append(Doc::text("}}"))
+        } else if matches!(ctx.language, Language::Glimmer) && !self.content.starts_with('{') {
+            let (content, wc_before, wc_after) = strip_hbs_whitespace_control(self.content.trim());
+            let content = helpers::format_glimmer_expr(content);
+            let mut parts = helpers::split_glimmer_args(&content).into_iter();
+            let mut docs = Vec::with_capacity(6);
+            docs.push(Doc::text("{{"));
+            if wc_before {
+                docs.push(Doc::char('~'));
+            }
+            if let Some(path) = parts.next() {
+                docs.push(glimmer_param_doc(path, ctx.indent_width));
+            }
+            docs.push(glimmer_params_doc(parts, ctx.indent_width));
+            docs.push(Doc::line_or_nil());
+            if wc_after {
+                docs.push(Doc::char('~'));
+            }
+            docs.push(Doc::text("}}"));
+            Doc::list(docs).group()
         } else {
             let content = if let Some(content) = self
                 .content
@@ -1151,9 +1539,68 @@ impl<'s> DocGen<'s> for NativeAttribute<'s> {
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
+        if matches!(ctx.language, Language::Glimmer) {
+            if self.value.is_none()
+                && let Some(params) = self
+                    .name
+                    .strip_prefix("as")
+                    .map(str::trim_start)
+                    .and_then(|s| s.strip_prefix('|'))
+                    .and_then(|s| s.strip_suffix('|'))
+            {
+                return Doc::text(format!(
+                    "as |{}|",
+                    params.split_ascii_whitespace().join(" ")
+                ));
+            }
+            // element modifiers like `{{on "click" this.go}}` and unquoted values like `@arg={{this.x}}`
+            let interpolation = match self.value {
+                None => self.name,
+                Some((value, _)) if self.quote.is_none() => value,
+                _ => "",
+            }
+            .strip_prefix("{{")
+            .and_then(|s| s.strip_suffix("}}"))
+            .filter(|s| !s.starts_with('{'))
+            .map(|content| MustacheInterpolation { content }.doc(ctx, state));
+            if let Some(interpolation) = interpolation {
+                return if self.value.is_some() {
+                    Doc::text(self.name)
+                        .append(Doc::char('='))
+                        .append(interpolation)
+                } else {
+                    interpolation
+                };
+            }
+        }
+
         let name = Doc::text(self.name);
         if let Some((value, value_start)) = self.value {
             let value = match ctx.language {
+                _ if ctx
+                    .options
+                    .json_attrs
+                    .iter()
+                    .any(|pattern| helpers::matches_attr_name_pattern(pattern, self.name))
+                    && !helpers::has_template_interpolation(value, ctx.language) =>
+                {
+                    Cow::from(format_json_attr_value(value, value_start, ctx))
+                }
+                Language::Blade
+                    if self.name.starts_with(':')
+                        && !self.name.starts_with("::")
+                        && state
+                            .current_tag_name
+                            .is_some_and(|name| name.starts_with("x-")) =>
+                {
+                    // values of component attributes are PHP expressions without HTML escaping
+                    let formatted = ctx.format_php_expr(value, value_start, state);
+                    if formatted.contains('"') && formatted.contains('\'') {
+                        Cow::from(value)
+                    } else {
+                        Cow::from(formatted)
+                    }
+                }
                 Language::Vue => {
                     if state
                         .current_tag_name
@@ -1209,7 +1656,22 @@ impl<'s> DocGen<'s> for NativeAttribute<'s> {
                     Cow::from(ctx.format_expr(value, false, value_start))
                 }
                 _ => {
-                    if !matches!(ctx.language, Language::Angular | Language::Xml)
+                    if let Some(formatted) = ctx
+                        .options
+                        .alpine
+                        .then(|| {
+                            format_alpine_attr_value(self.name, value, value_start, ctx, state)
+                        })
+                        .flatten()
+                    {
+                        Cow::from(formatted)
+                    } else if ctx.options.thymeleaf
+                        && (self.name.starts_with("th:") || self.name.starts_with("data-th-"))
+                    {
+                        Cow::from(helpers::format_thymeleaf_expr(value))
+                    } else if ctx.language == Language::GoTemplate && value.contains("{{") {
+                        helpers::format_go_template_attr_value(value)
+                    } else if !matches!(ctx.language, Language::Angular | Language::Xml)
                         && self.name.starts_with("on")
                     {
                         ctx.try_format_expr(value, true, value_start)
@@ -1260,15 +1722,28 @@ impl<'s> DocGen<'s> for NativeAttribute<'s> {
                             Language::Svelte => {
                                 Cow::from(format!("{{{}}}", ctx.format_expr(expr, true, *start),))
                             }
-                            Language::Jinja => Cow::from(format!(
-                                "{{{{ {} }}}}",
-                                ctx.format_jinja(expr, *start, true, state),
-                            )),
+                            Language::Jinja
+                            | Language::Liquid
+                            | Language::Django
+                            | Language::GoTemplate
+                            | Language::Erb
+                            | Language::Ejs
+                            | Language::Php
+                            | Language::Heex
+                            | Language::Custom(..) => {
+                                let (open, close) = ctx.jinja_dialect().interpolation();
+                                Cow::from(format!(
+                                    "{open} {} {close}",
+                                    ctx.format_jinja(expr, *start, true, state),
+                                ))
+                            }
                             Language::Vento => Cow::from(format!(
                                 "{{{{ {} }}}}",
                                 ctx.format_expr(expr, true, *start),
                             )),
-                            Language::Mustache => Cow::from(format!("{{{{{expr}}}}}")),
+                            Language::Mustache | Language::Glimmer => {
+                                Cow::from(format!("{{{{{expr}}}}}"))
+                            }
                             _ => unreachable!(),
                         }))
                         .collect::<String>(),
@@ -1333,6 +1808,10 @@ impl<'s> DocGen<'s> for NodeKind<'s> {
             NodeKind::AngularLet(angular_let) => angular_let.doc(ctx, state),
             NodeKind::AngularSwitch(angular_switch) => angular_switch.doc(ctx, state),
             NodeKind::AstroExpr(astro_expr) => astro_expr.doc(ctx, state),
+            NodeKind::BladeBlock(blade_block) => blade_block.doc(ctx, state),
+            NodeKind::BladeComment(blade_comment) => blade_comment.doc(ctx, state),
+            NodeKind::BladeDirective(blade_directive) => blade_directive.doc(ctx, state),
+            NodeKind::BladeEcho(blade_echo) => blade_echo.doc(ctx, state),
             NodeKind::Cdata(cdata) => cdata.doc(ctx, state),
             NodeKind::Comment(comment) => comment.doc(ctx, state),
             NodeKind::Doctype(doctype) => doctype.doc(ctx, state),
@@ -1344,10 +1823,17 @@ impl<'s> DocGen<'s> for NodeKind<'s> {
                 jinja_interpolation.doc(ctx, state)
             }
             NodeKind::JinjaTag(jinja_tag) => jinja_tag.doc(ctx, state),
+            NodeKind::MdxEsm(mdx_esm) => mdx_esm.doc(ctx, state),
+            NodeKind::MdxMarkdown(mdx_markdown) => mdx_markdown.doc(ctx, state),
             NodeKind::MustacheBlock(mustache_block) => mustache_block.doc(ctx, state),
             NodeKind::MustacheInterpolation(mustache_interpolation) => {
                 mustache_interpolation.doc(ctx, state)
             }
+            NodeKind::RazorBlock(razor_block) => razor_block.doc(ctx, state),
+            NodeKind::RazorCode(razor_code) => razor_code.doc(ctx, state),
+            NodeKind::RazorComment(razor_comment) => razor_comment.doc(ctx, state),
+            NodeKind::RazorDirective(razor_directive) => razor_directive.doc(ctx, state),
+            NodeKind::RazorExpr(razor_expr) => razor_expr.doc(ctx, state),
             NodeKind::SvelteAtTag(svelte_at_tag) => svelte_at_tag.doc(ctx, state),
             NodeKind::SvelteAwaitBlock(svelte_await_block) => svelte_await_block.doc(ctx, state),
             NodeKind::SvelteEachBlock(svelte_each_block) => svelte_each_block.doc(ctx, state),
@@ -1373,11 +1859,172 @@ impl<'s> DocGen<'s> for NodeKind<'s> {
     }
 }
 
+impl<'s> DocGen<'s> for RazorBlock<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let mut docs = Vec::with_capacity(self.branches.len() * 6);
+        for (i, branch) in self.branches.iter().enumerate() {
+            if i == 0 {
+                docs.push(Doc::char('@'));
+            } else {
+                docs.push(Doc::space());
+            }
+            docs.push(Doc::text(branch.keyword));
+            if let Some((header, start)) = branch.header {
+                docs.push(Doc::space());
+                let keyword = match branch.keyword {
+                    "else if" => "if",
+                    keyword => keyword,
+                };
+                if matches!(
+                    keyword,
+                    "if" | "for" | "foreach" | "while" | "switch" | "lock" | "using"
+                ) && let Some(code) = header
+                    .strip_prefix('(')
+                    .and_then(|header| header.strip_suffix(')'))
+                {
+                    docs.push(Doc::char('('));
+                    docs.extend(reflow_with_indent(
+                        &ctx.format_cs_stmt_header(keyword, code, start + 1),
+                        true,
+                    ));
+                    docs.push(Doc::char(')'));
+                } else {
+                    docs.extend(reflow_raw(header));
+                }
+            }
+            docs.push(Doc::text(" {"));
+            docs.push(format_control_structure_block_children(
+                &branch.children,
+                ctx,
+                state,
+            ));
+            docs.push(Doc::char('}'));
+        }
+        Doc::list(docs)
+    }
+}
+
+impl<'s> DocGen<'s> for RazorCode<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let mut docs = Vec::with_capacity(5);
+        docs.push(Doc::char('@'));
+        if let Some(keyword) = self.keyword {
+            docs.push(Doc::text(keyword));
+            docs.push(Doc::space());
+        }
+        docs.push(Doc::char('{'));
+        if helpers::razor_code_has_markup(self.code) {
+            // C# formatters can't handle markup, so the code is kept as is
+            docs.extend(reflow_raw(self.code));
+            docs.push(Doc::char('}'));
+            return Doc::list(docs);
+        }
+        let state = State {
+            indent_level: state.indent_level + 1,
+            ..state.clone()
+        };
+        let formatted = ctx.format_cs_block(self.code, self.start, &state);
+        if !formatted.is_empty() {
+            docs.push(
+                Doc::hard_line()
+                    .concat(reflow_with_indent(&formatted, true))
+                    .nest(ctx.indent_width),
+            );
+            docs.push(Doc::hard_line());
+        }
+        docs.push(Doc::char('}'));
+        Doc::list(docs)
+    }
+}
+
+impl<'s> DocGen<'s> for RazorComment<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, _: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        if ctx.options.format_comments {
+            Doc::text("@*")
+                .append(Doc::line_or_space())
+                .concat(reflow_with_indent(self.raw.trim(), true))
+                .nest(ctx.indent_width)
+                .append(Doc::line_or_space())
+                .append(Doc::text("*@"))
+                .group()
+        } else {
+            Doc::text("@*")
+                .concat(reflow_raw(self.raw))
+                .append(Doc::text("*@"))
+        }
+    }
+}
+
+impl<'s> DocGen<'s> for RazorDirective<'s> {
+    fn doc<F>(&self, _: &mut Ctx<'s, F>, _: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        let directive = Doc::char('@').append(Doc::text(self.name));
+        if self.value.is_empty() {
+            directive
+        } else {
+            directive.append(Doc::space()).append(Doc::text(self.value))
+        }
+    }
+}
+
+impl<'s> DocGen<'s> for RazorExpr<'s> {
+    fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
+    where
+        F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+    {
+        if self.explicit {
+            Doc::text("@(")
+                .concat(reflow_with_indent(
+                    &ctx.format_cs_expr(self.expr, self.start, state),
+                    true,
+                ))
+                .append(Doc::char(')'))
+        } else {
+            Doc::char('@').append(Doc::text(self.expr))
+        }
+    }
+}
+
 impl<'s> DocGen<'s> for Root<'s> {
     fn doc<F>(&self, ctx: &mut Ctx<'s, F>, state: &State<'s>) -> Doc<'s>
     where
         F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
     {
+        if ctx.language == Language::Mdx {
+            // blocks are separated by line breaks, and blank lines between them are kept
+            let mut docs = Vec::with_capacity(self.children.len() * 2);
+            let mut prev_end = None;
+            for child in &self.children {
+                if let Some(prev_end) = prev_end {
+                    docs.push(
+                        if ctx
+                            .source
+                            .get(prev_end..child.start)
+                            .is_some_and(|between| between.matches('\n').count() > 1)
+                        {
+                            Doc::empty_line().append(Doc::hard_line())
+                        } else {
+                            Doc::hard_line()
+                        },
+                    );
+                }
+                docs.push(child.kind.doc(ctx, state));
+                prev_end = Some(child.start + child.raw.len());
+            }
+            return Doc::list(docs).append(Doc::hard_line());
+        }
+
         let is_whole_document_like = self.children.iter().any(|child| match &child.kind {
             NodeKind::Doctype(..) => true,
             NodeKind::Element(element) => element.tag_name.eq_ignore_ascii_case("html"),
@@ -1746,6 +2393,15 @@ impl<'s> DocGen<'s> for TextNode<'s> {
             } else {
                 Doc::list(reflow_raw(self.raw.trim_ascii()).collect())
             }
+        } else if ctx.options.thymeleaf && (self.raw.contains("[[") || self.raw.contains("[(")) {
+            // inlined expressions can't be broken
+            let words = helpers::split_thymeleaf_text(self.raw);
+            Doc::flat_or_break(Doc::text(words.iter().join(" ")), {
+                Doc::list(
+                    itertools::intersperse(words.into_iter().map(Doc::text), Doc::soft_line())
+                        .collect(),
+                )
+            })
         } else {
             // for #16
             Doc::flat_or_break(Doc::text(self.raw.split_ascii_whitespace().join(" ")), {
@@ -2187,6 +2843,39 @@ impl<'s> DocGen<'s> for XmlDecl<'s> {
     }
 }
 
+/// Positional arguments, hash pairs and block params of Glimmer mustache or sub-expression.
+fn glimmer_params_doc<'s, 'p>(
+    params: impl Iterator<Item = &'p str>,
+    indent_width: usize,
+) -> Doc<'s> {
+    Doc::list(
+        params
+            .map(|param| Doc::line_or_space().append(glimmer_param_doc(param, indent_width)))
+            .collect(),
+    )
+    .nest(indent_width)
+}
+
+fn glimmer_param_doc<'s>(param: &str, indent_width: usize) -> Doc<'s> {
+    if let Some(expr) = param.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        let mut parts = helpers::split_glimmer_args(expr).into_iter();
+        let path = parts.next().unwrap_or_default();
+        Doc::char('(')
+            .append(glimmer_param_doc(path, indent_width))
+            .append(glimmer_params_doc(parts, indent_width))
+            .append(Doc::line_or_nil())
+            .append(Doc::char(')'))
+            .group()
+    } else if let Some((key, value)) = param.split_once('=')
+        && value.starts_with('(')
+        && !key.contains(['"', '\'', '('])
+    {
+        Doc::text(format!("{key}=")).append(glimmer_param_doc(value, indent_width))
+    } else {
+        Doc::text(param.to_owned())
+    }
+}
+
 fn reflow_raw(s: &str) -> impl Iterator<Item = Doc<'_>> {
     itertools::intersperse(
         s.split('\n')
@@ -2316,8 +3005,15 @@ fn is_multi_line_attr(attr: &Attribute) -> bool {
         | Attribute::SvelteAttachment(SvelteAttachment {
             expr: (value, ..), ..
         })
+        | Attribute::BladeEcho(BladeEcho { expr: value, .. })
+        | Attribute::Heex(HeexAttribute {
+            expr: (value, _), ..
+        })
         | Attribute::JinjaComment(JinjaComment { raw: value, .. })
         | Attribute::JinjaTag(JinjaTag { content: value, .. }) => value.contains('\n'),
+        Attribute::BladeDirective(directive) => {
+            directive.args.is_some_and(|(args, _)| args.contains('\n'))
+        }
         // Templating blocks usually span across multiple lines so let's just assume true.
         Attribute::JinjaBlock(..) | Attribute::VentoTagOrBlock(..) => true,
         Attribute::JsComment(comment) => comment.raw.contains('\n'),
@@ -2525,7 +3221,13 @@ fn is_text_like(node: &Node, language: Language) -> bool {
         | NodeKind::SvelteInterpolation(..)
         | NodeKind::AstroExpr(..)
         | NodeKind::JinjaInterpolation(..)
-        | NodeKind::VentoInterpolation(..) => true,
+        | NodeKind::VentoInterpolation(..)
+        | NodeKind::BladeEcho(..)
+        | NodeKind::RazorExpr(..) => true,
+        // tags may output values if they look the same as interpolations, like Go template
+        NodeKind::JinjaTag(..) => language
+            .dialect()
+            .is_some_and(|dialect| dialect.tag() == dialect.interpolation()),
         _ => false,
     }
 }
@@ -2714,6 +3416,120 @@ where
     }
 }
 
+/// Format attribute value which is JSON or JavaScript object literal.
+/// Value prefixed with `js:` or `javascript:` (as htmx does) is always treated as JavaScript.
+fn format_json_attr_value<'s, F>(value: &str, value_start: usize, ctx: &mut Ctx<'s, F>) -> String
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    let trimmed = value.trim();
+    if let Some((prefix, expr)) = ["js:", "javascript:"]
+        .into_iter()
+        .find_map(|prefix| Some((prefix, trimmed.strip_prefix(prefix)?)))
+    {
+        let expr_start = value_start + (value.len() - expr.len());
+        ctx.with_escaping_quotes(expr, |code, ctx| {
+            format!("{prefix}{}", ctx.format_expr(&code, true, expr_start))
+        })
+    } else if trimmed.starts_with(['{', '[']) {
+        ctx.with_escaping_quotes(value, |code, ctx| {
+            ctx.try_format_json_attr(&code, value_start)
+                .unwrap_or_else(|_| ctx.format_expr(&code, true, value_start))
+        })
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Format JavaScript in Alpine.js directives, returning `None` for other attributes.
+fn format_alpine_attr_value<'s, F>(
+    name: &str,
+    value: &str,
+    value_start: usize,
+    ctx: &mut Ctx<'s, F>,
+    state: &State<'s>,
+) -> Option<String>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    if value.trim().is_empty() || helpers::has_template_interpolation(value, ctx.language) {
+        return None;
+    }
+    let directive = name.split('.').next().unwrap_or(name);
+    if directive == "x-for" {
+        let (left, right) = value.split_once(" in ")?;
+        Some(
+            ctx.with_escaping_quotes(&format!("{left} in {right}"), |code, ctx| {
+                let (left, right) = code.split_once(" in ").unwrap_or((&code, ""));
+                format_v_for(left, "in", right, value_start, ctx)
+            }),
+        )
+    } else if directive.starts_with('@')
+        || directive.starts_with("x-on:")
+        || matches!(directive, "x-init" | "x-effect")
+    {
+        Some(ctx.with_escaping_quotes(value, |code, ctx| {
+            ctx.try_format_expr(&code, true, value_start)
+                .unwrap_or_else(|_| {
+                    let formatted = ctx
+                        .format_script(&code, "js", value_start, state)
+                        .trim()
+                        .to_owned();
+                    if formatted.contains('\n') {
+                        formatted
+                    } else {
+                        formatted.trim_end_matches(';').to_owned()
+                    }
+                })
+        }))
+    } else if directive.starts_with(':')
+        || directive.starts_with("x-bind:")
+        || matches!(
+            directive,
+            "x-data"
+                | "x-bind"
+                | "x-show"
+                | "x-model"
+                | "x-modelable"
+                | "x-text"
+                | "x-html"
+                | "x-if"
+                | "x-id"
+        )
+    {
+        Some(ctx.with_escaping_quotes(value, |code, ctx| ctx.format_expr(&code, true, value_start)))
+    } else {
+        None
+    }
+}
+
+/// Format content of top-level block whose language isn't HTML,
+/// such as Vue custom blocks, by external formatter.
+/// Indentation of content is significant in some languages like YAML,
+/// so it's kept as is if external formatter doesn't change it.
+fn format_raw_block_content<'s, F>(
+    text_node: &TextNode<'s>,
+    lang: Option<&'s str>,
+    ctx: &mut Ctx<'s, F>,
+    state: &State<'s>,
+) -> Doc<'s>
+where
+    F: for<'a> FnMut(&'a str, Hints) -> Result<Cow<'a, str>, Error>,
+{
+    let formatted = lang
+        .map(|lang| ctx.format_script(text_node.raw, lang, text_node.start, state))
+        .filter(|formatted| formatted != text_node.raw);
+    if let Some(formatted) = formatted {
+        Doc::hard_line()
+            .concat(reflow_owned(
+                formatted.trim_start_matches(['\n', '\r']).trim_end(),
+            ))
+            .append(Doc::hard_line())
+    } else {
+        Doc::list(reflow_raw(text_node.raw).collect())
+    }
+}
+
 fn format_control_structure_block_children<'s, F>(
     children: &[Node<'s>],
     ctx: &mut Ctx<'s, F>,
@@ -0,0 +1,415 @@
+//! Folding ranges and document outline derived from AST for editor integration.
+
+use crate::{Language, ast::*, helpers};
+#[cfg(feature = "ast_serde")]
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub enum FoldingRangeKind {
+    Comment,
+    Element,
+    /// Content of `<script>` or `<style>` element.
+    Code,
+    /// Template control flow block or one of its branches, such as `{:else}` of Svelte.
+    Block,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub struct FoldingRange {
+    pub kind: FoldingRangeKind,
+    /// Start byte offset in source code.
+    pub start: usize,
+    /// End byte offset in source code. (exclusive)
+    pub end: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub enum SymbolKind {
+    /// Element with `id` attribute.
+    Element,
+    Component,
+    SvelteSnippet,
+    JinjaBlock,
+    JinjaMacro,
+    AngularDefer,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub struct DocumentSymbol<'s> {
+    pub name: Cow<'s, str>,
+    pub kind: SymbolKind,
+    /// Start byte offset in source code.
+    pub start: usize,
+    /// End byte offset in source code. (exclusive)
+    pub end: usize,
+    pub children: Vec<DocumentSymbol<'s>>,
+}
+
+/// Collect folding ranges which span multiple lines.
+///
+/// ```
+/// use markup_fmt::{parse, structure::{folding_ranges, FoldingRangeKind}, Language};
+///
+/// let code = "{#if a}\n  <p>\n    a\n  </p>\n{:else}\n  b\n{/if}";
+/// let root = parse(code, Language::Svelte).unwrap();
+/// let ranges = folding_ranges(&root, code)
+///     .into_iter()
+///     .map(|range| (range.kind, &code[range.start..range.end]))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     ranges,
+///     [
+///         (FoldingRangeKind::Block, code),
+///         (FoldingRangeKind::Element, "<p>\n    a\n  </p>"),
+///         (FoldingRangeKind::Block, "\n  b\n"),
+///     ],
+/// );
+/// ```
+pub fn folding_ranges(root: &Root, code: &str) -> Vec<FoldingRange> {
+    let mut ranges = vec![];
+    collect_folding_ranges(&root.children, code, &mut ranges);
+    ranges
+}
+
+fn collect_folding_ranges(nodes: &[Node], code: &str, ranges: &mut Vec<FoldingRange>) {
+    for node in nodes {
+        let kind = match &node.kind {
+            NodeKind::Comment(..)
+            | NodeKind::BladeComment(..)
+            | NodeKind::RazorComment(..)
+            | NodeKind::JinjaComment(..)
+            | NodeKind::VentoComment(..) => Some(FoldingRangeKind::Comment),
+            NodeKind::Element(..) => Some(FoldingRangeKind::Element),
+            NodeKind::AngularFor(..)
+            | NodeKind::AngularGenericBlocks(..)
+            | NodeKind::AngularIf(..)
+            | NodeKind::AngularSwitch(..)
+            | NodeKind::BladeBlock(..)
+            | NodeKind::JinjaBlock(..)
+            | NodeKind::MustacheBlock(..)
+            | NodeKind::RazorBlock(..)
+            | NodeKind::RazorCode(..)
+            | NodeKind::SvelteAwaitBlock(..)
+            | NodeKind::SvelteEachBlock(..)
+            | NodeKind::SvelteIfBlock(..)
+            | NodeKind::SvelteKeyBlock(..)
+            | NodeKind::SvelteSnippetBlock(..)
+            | NodeKind::VentoBlock(..) => Some(FoldingRangeKind::Block),
+            _ => None,
+        };
+        if let Some(kind) = kind
+            && node.raw.contains('\n')
+        {
+            ranges.push(FoldingRange {
+                kind,
+                start: node.start,
+                end: node.start + node.raw.len(),
+            });
+        }
+
+        if let NodeKind::Element(element) = &node.kind
+            && (element.tag_name.eq_ignore_ascii_case("script")
+                || element.tag_name.eq_ignore_ascii_case("style"))
+        {
+            push_children_range(&element.children, FoldingRangeKind::Code, code, ranges);
+            continue;
+        }
+
+        let branches = child_branches(&node.kind);
+        let is_block = kind == Some(FoldingRangeKind::Block);
+        for (i, children) in branches.into_iter().enumerate() {
+            // the first branch is covered by the whole block
+            if is_block && i > 0 {
+                push_children_range(children, FoldingRangeKind::Block, code, ranges);
+            }
+            collect_folding_ranges(children, code, ranges);
+        }
+    }
+}
+
+fn push_children_range(
+    children: &[Node],
+    kind: FoldingRangeKind,
+    code: &str,
+    ranges: &mut Vec<FoldingRange>,
+) {
+    if let Some((first, last)) = children.first().zip(children.last()) {
+        let start = first.start;
+        let end = last.start + last.raw.len();
+        if code.get(start..end).is_some_and(|s| s.contains('\n')) {
+            ranges.push(FoldingRange { kind, start, end });
+        }
+    }
+}
+
+/// Collect outline of document, such as elements with `id`, components and template blocks.
+///
+/// Symbols are nested by their positions in AST.
+///
+/// ```
+/// use markup_fmt::{parse, structure::{document_symbols, SymbolKind}, Language};
+///
+/// let code = "<main id=\"app\"><MyButton /></main>";
+/// let root = parse(code, Language::Vue).unwrap();
+/// let symbols = document_symbols(&root, Language::Vue);
+/// assert_eq!(symbols[0].name, "main#app");
+/// assert_eq!(symbols[0].kind, SymbolKind::Element);
+/// assert_eq!(symbols[0].children[0].name, "MyButton");
+/// assert_eq!(symbols[0].children[0].kind, SymbolKind::Component);
+/// ```
+pub fn document_symbols<'s>(root: &Root<'s>, language: Language) -> Vec<DocumentSymbol<'s>> {
+    let mut symbols = vec![];
+    collect_symbols(&root.children, language, &mut symbols);
+    symbols
+}
+
+fn collect_symbols<'s>(
+    nodes: &[Node<'s>],
+    language: Language,
+    symbols: &mut Vec<DocumentSymbol<'s>>,
+) {
+    for node in nodes {
+        let symbol = match &node.kind {
+            NodeKind::Element(element)
+                if matches!(
+                    language,
+                    Language::Vue
+                        | Language::Svelte
+                        | Language::Astro
+                        | Language::Mdx
+                        | Language::Angular
+                ) && helpers::is_component(element.tag_name) =>
+            {
+                Some((Cow::from(element.tag_name), SymbolKind::Component))
+            }
+            NodeKind::Element(element) => element
+                .attrs
+                .iter()
+                .find_map(|attr| match attr {
+                    Attribute::Native(native) if native.name.eq_ignore_ascii_case("id") => {
+                        native.value.map(|(value, _)| value)
+                    }
+                    _ => None,
+                })
+                .map(|id| {
+                    (
+                        Cow::from(format!("{}#{id}", element.tag_name)),
+                        SymbolKind::Element,
+                    )
+                }),
+            NodeKind::SvelteSnippetBlock(block) => Some((
+                Cow::from(
+                    block
+                        .signature
+                        .0
+                        .split_once(['(', '<'])
+                        .map_or(block.signature.0, |(name, _)| name)
+                        .trim(),
+                ),
+                SymbolKind::SvelteSnippet,
+            )),
+            NodeKind::JinjaBlock(JinjaBlock { body }) => match body.first() {
+                Some(JinjaTagOrChildren::Tag(tag)) => {
+                    let content = tag.content.trim_matches(['+', '-']).trim();
+                    // keyword must be a whole word, so `blocktrans` isn't a block named `trans`
+                    let strip_keyword = |keyword| {
+                        content.strip_prefix(keyword).filter(|rest: &&str| {
+                            rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_whitespace())
+                        })
+                    };
+                    if let Some(rest) = strip_keyword("block") {
+                        Some((Cow::from(rest.trim()), SymbolKind::JinjaBlock))
+                    } else {
+                        strip_keyword("macro").map(|rest| {
+                            (
+                                Cow::from(
+                                    rest.split_once('(').map_or(rest, |(name, _)| name).trim(),
+                                ),
+                                SymbolKind::JinjaMacro,
+                            )
+                        })
+                    }
+                }
+                _ => None,
+            },
+            NodeKind::AngularGenericBlocks(blocks) => blocks
+                .first()
+                .filter(|block| block.keyword == "defer")
+                .map(|block| {
+                    let name = if let Some(header) = block.header {
+                        Cow::from(format!("@defer {}", header.trim()))
+                    } else {
+                        Cow::from("@defer")
+                    };
+                    (name, SymbolKind::AngularDefer)
+                }),
+            _ => None,
+        };
+
+        let mut children = vec![];
+        for branch in child_branches(&node.kind) {
+            collect_symbols(branch, language, &mut children);
+        }
+        if let Some((name, kind)) = symbol {
+            symbols.push(DocumentSymbol {
+                name,
+                kind,
+                start: node.start,
+                end: node.start + node.raw.len(),
+                children,
+            });
+        } else {
+            symbols.append(&mut children);
+        }
+    }
+}
+
+/// Lists of child nodes. For template blocks, each list is a branch of that block.
+fn child_branches<'a, 's>(kind: &'a NodeKind<'s>) -> Vec<&'a [Node<'s>]> {
+    match kind {
+        NodeKind::Element(element) => vec![&element.children],
+        NodeKind::AngularFor(angular_for) => {
+            let mut branches = vec![&*angular_for.children];
+            branches.extend(angular_for.empty.as_deref());
+            branches
+        }
+        NodeKind::AngularGenericBlocks(blocks) => {
+            blocks.iter().map(|block| &*block.children).collect()
+        }
+        NodeKind::AngularIf(angular_if) => {
+            let mut branches = vec![&*angular_if.children];
+            branches.extend(
+                angular_if
+                    .else_if_blocks
+                    .iter()
+                    .map(|block| &*block.children),
+            );
+            branches.extend(angular_if.else_children.as_deref());
+            branches
+        }
+        NodeKind::AngularSwitch(angular_switch) => angular_switch
+            .arms
+            .iter()
+            .filter_map(|arm| arm.children.as_deref())
+            .collect(),
+        NodeKind::AstroExpr(astro_expr) => astro_expr
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                AstroExprChild::Template(nodes) => Some(&**nodes),
+                AstroExprChild::Script(..) => None,
+            })
+            .collect(),
+        NodeKind::BladeBlock(block) => block.children.iter().map(|nodes| &**nodes).collect(),
+        NodeKind::JinjaBlock(block) => block
+            .body
+            .iter()
+            .filter_map(|child| match child {
+                JinjaTagOrChildren::Children(nodes) => Some(&**nodes),
+                JinjaTagOrChildren::Tag(..) => None,
+            })
+            .collect(),
+        NodeKind::MustacheBlock(block) => block.children.iter().map(|nodes| &**nodes).collect(),
+        NodeKind::RazorBlock(block) => block
+            .branches
+            .iter()
+            .map(|branch| &*branch.children)
+            .collect(),
+        NodeKind::SvelteAwaitBlock(block) => {
+            let mut branches = vec![&*block.children];
+            branches.extend(block.then_block.as_ref().map(|block| &*block.children));
+            branches.extend(block.catch_block.as_ref().map(|block| &*block.children));
+            branches
+        }
+        NodeKind::SvelteEachBlock(block) => {
+            let mut branches = vec![&*block.children];
+            branches.extend(block.else_children.as_deref());
+            branches
+        }
+        NodeKind::SvelteIfBlock(block) => {
+            let mut branches = vec![&*block.children];
+            branches.extend(block.else_if_blocks.iter().map(|block| &*block.children));
+            branches.extend(block.else_children.as_deref());
+            branches
+        }
+        NodeKind::SvelteKeyBlock(block) => vec![&block.children],
+        NodeKind::SvelteSnippetBlock(block) => vec![&block.children],
+        NodeKind::VentoBlock(block) => block
+            .body
+            .iter()
+            .filter_map(|child| match child {
+                VentoTagOrChildren::Children(nodes) => Some(&**nodes),
+                VentoTagOrChildren::Tag(..) => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn jinja_symbols() {
+        let code = "{% macro input(name) %}<input>{% endmacro %}\n{% block content %}<div id=main>{% block inner %}{% endblock %}</div>{% endblock %}";
+        let root = parse(code, Language::Jinja).unwrap();
+        let symbols = document_symbols(&root, Language::Jinja);
+        assert_eq!(
+            symbols
+                .iter()
+                .map(|symbol| (symbol.name.as_ref(), symbol.kind))
+                .collect::<Vec<_>>(),
+            [
+                ("input", SymbolKind::JinjaMacro),
+                ("content", SymbolKind::JinjaBlock)
+            ]
+        );
+        let div = &symbols[1].children[0];
+        assert_eq!(
+            (div.name.as_ref(), div.kind),
+            ("div#main", SymbolKind::Element)
+        );
+        assert_eq!(div.children[0].name, "inner");
+    }
+
+    #[test]
+    fn django_blocktrans() {
+        let code = "{% blocktrans %}Hello{% endblocktrans %}{% block title %}{% blockfoo %}{% endblockfoo %}{% endblock %}";
+        let root = parse(code, Language::Django).unwrap();
+        let symbols = document_symbols(&root, Language::Django);
+        assert_eq!(
+            symbols
+                .iter()
+                .map(|symbol| (symbol.name.as_ref(), symbol.kind))
+                .collect::<Vec<_>>(),
+            [("title", SymbolKind::JinjaBlock)]
+        );
+        assert!(symbols[0].children.is_empty());
+    }
+
+    #[test]
+    fn angular_folding_and_defer() {
+        let code = "@defer (on viewport) {\n  <big-cmp />\n} @placeholder {\n  <p>...</p>\n}\n<script>\n  a;\n</script>";
+        let root = parse(code, Language::Angular).unwrap();
+        let ranges = folding_ranges(&root, code)
+            .into_iter()
+            .map(|range| (range.kind, &code[range.start..range.end]))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges[0].0, FoldingRangeKind::Block);
+        assert_eq!(ranges[1], (FoldingRangeKind::Block, "\n  <p>...</p>\n"));
+        assert_eq!(ranges[2].0, FoldingRangeKind::Element);
+        assert_eq!(ranges[3], (FoldingRangeKind::Code, "\n  a;\n"));
+
+        let symbols = document_symbols(&root, Language::Angular);
+        assert_eq!(symbols[0].name, "@defer (on viewport)");
+        assert_eq!(symbols[0].kind, SymbolKind::AngularDefer);
+        assert_eq!(symbols[0].children[0].name, "big-cmp");
+    }
+}
@@ -0,0 +1,904 @@
+//! Flat list of semantic tokens for syntax highlighting.
+//!
+//! Tokens are derived from the same AST used by the formatter,
+//! so editors can highlight code with the exact grammar of markup_fmt.
+
+use crate::{
+    Language, SyntaxError,
+    ast::*,
+    config::LanguageOptions,
+    dialect::{self, Dialect},
+    helpers::{self, ScriptKind},
+    parser::Parser,
+};
+#[cfg(feature = "ast_serde")]
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub enum TokenKind<'s> {
+    /// Tag name of opening tag or closing tag.
+    TagName,
+    AttributeName,
+    /// Attribute value without quotes, which isn't code.
+    AttributeValue,
+    /// Name of directive, such as `v-if`, `:`, `@` in Vue or `on` in `on:click` of Svelte.
+    DirectiveName,
+    DirectiveArgument,
+    DirectiveModifier,
+    /// Delimiters of interpolations and template tags, such as `{{` and `}}`.
+    Delimiter,
+    /// Embedded code. `ext` is the same as [`Hints::ext`](crate::Hints::ext)
+    /// which will be passed to external formatter.
+    Code {
+        ext: &'s str,
+    },
+    Comment,
+    Text,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "ast_serde", derive(Serialize))]
+pub struct Token<'s> {
+    pub kind: TokenKind<'s>,
+    /// Start byte offset in source code.
+    pub start: usize,
+    /// End byte offset in source code. (exclusive)
+    pub end: usize,
+}
+
+/// Parse the given source code and collect semantic tokens.
+///
+/// ```
+/// use markup_fmt::{tokens::{tokenize, TokenKind}, Language};
+///
+/// let code = "<p :title=\"msg\">{{ msg }}</p>";
+/// let tokens = tokenize(code, Language::Vue, &Default::default()).unwrap();
+/// let kinds = tokens
+///     .iter()
+///     .map(|token| (token.kind, &code[token.start..token.end]))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     kinds,
+///     [
+///         (TokenKind::TagName, "p"),
+///         (TokenKind::DirectiveName, ":"),
+///         (TokenKind::DirectiveArgument, "title"),
+///         (TokenKind::Code { ext: "js" }, "msg"),
+///         (TokenKind::Delimiter, "{{"),
+///         (TokenKind::Code { ext: "js" }, " msg "),
+///         (TokenKind::Delimiter, "}}"),
+///         (TokenKind::TagName, "p"),
+///     ],
+/// );
+/// ```
+pub fn tokenize<'s>(
+    code: &'s str,
+    language: Language,
+    options: &LanguageOptions,
+) -> Result<Vec<Token<'s>>, SyntaxError> {
+    let root = Parser::new(code, language).parse_root()?;
+    Ok(collect(&root, code, language, options))
+}
+
+/// Collect semantic tokens from parsed AST.
+///
+/// Nodes and attribute values that aren't parsed from `code`, such as ones created by transform hook,
+/// are skipped.
+/// Tokens are sorted by their positions.
+/// Extension of code tokens for template expressions is the same as the one used when formatting.
+pub fn collect<'s>(
+    root: &Root<'s>,
+    code: &'s str,
+    language: Language,
+    options: &LanguageOptions,
+) -> Vec<Token<'s>> {
+    let mut collector = Collector {
+        source: code,
+        language,
+        dialect: language.dialect().unwrap_or(&dialect::Jinja),
+        expr_ext: helpers::expr_ext(language, &root.children, options),
+        tokens: vec![],
+    };
+    collector.nodes(&root.children);
+    collector.tokens.sort_by_key(|token| token.start);
+    collector.tokens
+}
+
+struct Collector<'s> {
+    source: &'s str,
+    language: Language,
+    dialect: &'static dyn Dialect,
+    expr_ext: &'static str,
+    tokens: Vec<Token<'s>>,
+}
+
+impl<'s> Collector<'s> {
+    fn push(&mut self, kind: TokenKind<'s>, start: usize, end: usize) {
+        if start < end && end <= self.source.len() {
+            self.tokens.push(Token { kind, start, end });
+        }
+    }
+
+    /// Byte offset of a string slice which borrows from source code.
+    fn offset_of(&self, s: &str) -> Option<usize> {
+        (s.as_ptr() as usize)
+            .checked_sub(self.source.as_ptr() as usize)
+            .filter(|offset| offset + s.len() <= self.source.len())
+    }
+
+    fn slice(&mut self, kind: TokenKind<'s>, s: &str) {
+        if let Some(start) = self.offset_of(s) {
+            self.push(kind, start, start + s.len());
+        }
+    }
+
+    fn code(&mut self, ext: &'s str, (code, start): (&str, usize)) {
+        if self.offset_of(code) == Some(start) {
+            self.push(TokenKind::Code { ext }, start, start + code.len());
+        }
+    }
+
+    fn text(&mut self, raw: &str, start: usize) {
+        let trimmed = raw.trim_start();
+        let start = start + raw.len() - trimmed.len();
+        self.push(TokenKind::Text, start, start + trimmed.trim_end().len());
+    }
+
+    /// Delimiters around code which is inside a node spanning `start..end`.
+    fn delimiters(&mut self, (start, end): (usize, usize), (code, code_start): (&str, usize)) {
+        let code_end = code_start + code.len();
+        if let Some(open) = self.source.get(start..code_start) {
+            self.push(TokenKind::Delimiter, start, start + open.trim_end().len());
+        }
+        if let Some(close) = self.source.get(code_end..end) {
+            self.push(TokenKind::Delimiter, end - close.trim_start().len(), end);
+        }
+    }
+
+    /// Delimiters around code with known opening and closing strings.
+    fn known_delimiters(&mut self, (open, close): (&str, &str), start: usize, end: usize) {
+        if let Some(open_start) = start.checked_sub(open.len())
+            && self.source.get(open_start..start) == Some(open)
+        {
+            self.push(TokenKind::Delimiter, open_start, start);
+        }
+        if self
+            .source
+            .get(end..)
+            .is_some_and(|rest| rest.starts_with(close))
+        {
+            self.push(TokenKind::Delimiter, end, end + close.len());
+        }
+    }
+
+    fn nodes(&mut self, nodes: &[Node<'s>]) {
+        nodes.iter().for_each(|node| self.node(node));
+    }
+
+    fn node(&mut self, node: &Node<'s>) {
+        if self.offset_of(node.raw) != Some(node.start) {
+            // offsets of nodes created by transform hook are relative to their own snippets
+            return;
+        }
+        let span = (node.start, node.start + node.raw.len());
+        match &node.kind {
+            NodeKind::Element(element) => self.element(element, node),
+            NodeKind::Comment(..) => self.push(TokenKind::Comment, span.0, span.1),
+            NodeKind::Text(text_node) => self.text(text_node.raw, text_node.start),
+            NodeKind::Cdata(cdata) => {
+                if let Some(start) = self.offset_of(cdata.raw) {
+                    self.text(cdata.raw, start);
+                }
+            }
+            NodeKind::VueInterpolation(VueInterpolation { expr, start })
+            | NodeKind::AngularInterpolation(AngularInterpolation { expr, start }) => {
+                self.delimiters(span, (expr, *start));
+                self.code(self.expr_ext, (expr, *start));
+            }
+            NodeKind::SvelteInterpolation(SvelteInterpolation { expr }) => {
+                self.delimiters(span, *expr);
+                self.code(self.expr_ext, *expr);
+            }
+            NodeKind::MustacheInterpolation(MustacheInterpolation { content }) => {
+                if let Some(start) = self.offset_of(content) {
+                    self.delimiters(span, (content, start));
+                }
+            }
+            NodeKind::AstroExpr(astro_expr) => {
+                self.push(TokenKind::Delimiter, span.0, span.0 + 1);
+                astro_expr.children.iter().for_each(|child| match child {
+                    AstroExprChild::Script(script) => {
+                        self.slice(TokenKind::Code { ext: self.expr_ext }, script)
+                    }
+                    AstroExprChild::Template(nodes) => self.nodes(nodes),
+                });
+                self.push(TokenKind::Delimiter, span.1 - 1, span.1);
+            }
+            NodeKind::RazorComment(..) => self.push(TokenKind::Comment, span.0, span.1),
+            NodeKind::RazorDirective(directive) => {
+                self.push(
+                    TokenKind::DirectiveName,
+                    span.0,
+                    span.0 + 1 + directive.name.len(),
+                );
+                self.slice(TokenKind::Code { ext: "cs" }, directive.value);
+            }
+            NodeKind::RazorExpr(RazorExpr {
+                expr,
+                start,
+                explicit,
+            }) => {
+                if *explicit {
+                    self.delimiters(span, (expr, *start));
+                } else {
+                    self.push(TokenKind::Delimiter, span.0, span.0 + 1);
+                }
+                self.code("cs", (expr, *start));
+            }
+            NodeKind::FrontMatter(front_matter) => {
+                self.push(TokenKind::Delimiter, span.0, span.0 + 3);
+                let ext = if self.language == Language::Astro {
+                    "tsx"
+                } else {
+                    "yaml"
+                };
+                self.code(ext, (front_matter.raw, front_matter.start));
+                self.push(TokenKind::Delimiter, span.1 - 3, span.1);
+            }
+            kind => self.node_kind(kind),
+        }
+    }
+
+    /// Node kinds which don't rely on positions of whole node,
+    /// so they can be shared with attributes.
+    fn node_kind(&mut self, kind: &NodeKind<'s>) {
+        match kind {
+            NodeKind::AngularFor(angular_for) => {
+                self.code("ts", angular_for.binding);
+                self.code(self.expr_ext, angular_for.expr);
+                if let Some(track) = angular_for.track {
+                    self.code(self.expr_ext, track);
+                }
+                for alias in &angular_for.aliases {
+                    self.code("js", *alias);
+                }
+                self.nodes(&angular_for.children);
+                if let Some(empty) = &angular_for.empty {
+                    self.nodes(empty);
+                }
+            }
+            NodeKind::AngularGenericBlocks(blocks) => {
+                for block in blocks {
+                    self.nodes(&block.children);
+                }
+            }
+            NodeKind::AngularIf(angular_if) => {
+                self.code(self.expr_ext, angular_if.expr);
+                if let Some(reference) = angular_if.reference {
+                    self.code("ts", reference);
+                }
+                self.nodes(&angular_if.children);
+                for else_if in &angular_if.else_if_blocks {
+                    self.code(self.expr_ext, else_if.expr);
+                    if let Some(reference) = else_if.reference {
+                        self.code("ts", reference);
+                    }
+                    self.nodes(&else_if.children);
+                }
+                if let Some(children) = &angular_if.else_children {
+                    self.nodes(children);
+                }
+            }
+            NodeKind::AngularLet(angular_let) => self.code(self.expr_ext, angular_let.expr),
+            NodeKind::AngularSwitch(angular_switch) => {
+                self.code(self.expr_ext, angular_switch.expr);
+                for arm in &angular_switch.arms {
+                    if let Some(expr) = arm.expr {
+                        self.code(self.expr_ext, expr);
+                    }
+                    if let Some(children) = &arm.children {
+                        self.nodes(children);
+                    }
+                }
+            }
+            NodeKind::BladeBlock(block) => {
+                for (i, directive) in block.directives.iter().enumerate() {
+                    self.blade_directive(directive);
+                    if let Some(children) = block.children.get(i) {
+                        self.nodes(children);
+                    }
+                }
+            }
+            NodeKind::BladeComment(comment) => {
+                if let Some(start) = self.offset_of(comment.raw) {
+                    let end = start + comment.raw.len();
+                    self.push(TokenKind::Comment, start.saturating_sub(4), end + 4);
+                }
+            }
+            NodeKind::BladeDirective(directive) => self.blade_directive(directive),
+            NodeKind::BladeEcho(echo) => self.blade_echo(echo),
+            NodeKind::JinjaBlock(block) => {
+                for child in &block.body {
+                    match child {
+                        JinjaTagOrChildren::Tag(tag) => self.jinja_tag(tag),
+                        JinjaTagOrChildren::Children(nodes) => self.nodes(nodes),
+                    }
+                }
+            }
+            NodeKind::JinjaComment(comment) => self.jinja_comment(comment),
+            NodeKind::JinjaInterpolation(interpolation) => {
+                let start = interpolation.start - usize::from(interpolation.trim_prev);
+                let end = interpolation.start
+                    + interpolation.expr.len()
+                    + usize::from(interpolation.trim_next);
+                self.known_delimiters(self.dialect.interpolation(), start, end);
+                self.code(
+                    self.dialect.external_formatter_exts().0,
+                    (interpolation.expr, interpolation.start),
+                );
+            }
+            NodeKind::JinjaTag(tag) => self.jinja_tag(tag),
+            NodeKind::MdxEsm(esm) => self.code("tsx", (esm.raw, esm.start)),
+            NodeKind::MdxMarkdown(markdown) => self.code("md", (markdown.raw, markdown.start)),
+            NodeKind::MustacheBlock(block) => {
+                for children in &block.children {
+                    self.nodes(children);
+                }
+            }
+            NodeKind::RazorBlock(block) => {
+                for branch in &block.branches {
+                    self.slice(TokenKind::DirectiveName, branch.keyword);
+                    if let Some(header) = branch.header {
+                        self.code("cs", header);
+                    }
+                    self.nodes(&branch.children);
+                }
+            }
+            NodeKind::RazorCode(code) => self.code("cs", (code.code, code.start)),
+            NodeKind::SvelteAtTag(at_tag) => self.code(self.expr_ext, at_tag.expr),
+            NodeKind::SvelteAwaitBlock(block) => {
+                self.code(self.expr_ext, block.expr);
+                if let Some(Some(binding)) = block.then_binding {
+                    self.code("ts", binding);
+                }
+                if let Some(Some(binding)) = block.catch_binding {
+                    self.code("ts", binding);
+                }
+                self.nodes(&block.children);
+                if let Some(then_block) = &block.then_block {
+                    if let Some(binding) = then_block.binding {
+                        self.code("ts", binding);
+                    }
+                    self.nodes(&then_block.children);
+                }
+                if let Some(catch_block) = &block.catch_block {
+                    if let Some(binding) = catch_block.binding {
+                        self.code("ts", binding);
+                    }
+                    self.nodes(&catch_block.children);
+                }
+            }
+            NodeKind::SvelteEachBlock(block) => {
+                self.code(self.expr_ext, block.expr);
+                if let Some(binding) = block.binding {
+                    self.code("ts", binding);
+                }
+                if let Some(key) = block.key {
+                    self.code(self.expr_ext, key);
+                }
+                self.nodes(&block.children);
+                if let Some(children) = &block.else_children {
+                    self.nodes(children);
+                }
+            }
+            NodeKind::SvelteIfBlock(block) => {
+                self.code(self.expr_ext, block.expr);
+                self.nodes(&block.children);
+                for else_if in &block.else_if_blocks {
+                    self.code(self.expr_ext, else_if.expr);
+                    self.nodes(&else_if.children);
+                }
+                if let Some(children) = &block.else_children {
+                    self.nodes(children);
+                }
+            }
+            NodeKind::SvelteKeyBlock(block) => {
+                self.code(self.expr_ext, block.expr);
+                self.nodes(&block.children);
+            }
+            NodeKind::SvelteSnippetBlock(block) => {
+                self.code("ts", block.signature);
+                self.nodes(&block.children);
+            }
+            NodeKind::VentoBlock(block) => {
+                for child in &block.body {
+                    match child {
+                        VentoTagOrChildren::Tag(tag) => self.vento_tag(tag.tag),
+                        VentoTagOrChildren::Children(nodes) => self.nodes(nodes),
+                    }
+                }
+            }
+            NodeKind::VentoComment(comment) => {
+                if let Some(start) = self.offset_of(comment.raw) {
+                    self.push(TokenKind::Comment, start - 3, start + comment.raw.len() + 3);
+                }
+            }
+            NodeKind::VentoEval(eval) => {
+                self.known_delimiters(("{{>", "}}"), eval.start, eval.start + eval.raw.len());
+                self.code("js", (eval.raw, eval.start));
+            }
+            NodeKind::VentoInterpolation(interpolation) => {
+                let start = interpolation.start - usize::from(interpolation.trim_prev);
+                let end = interpolation.start
+                    + interpolation.expr.len()
+                    + usize::from(interpolation.trim_next);
+                self.known_delimiters(("{{", "}}"), start, end);
+                self.code(self.expr_ext, (interpolation.expr, interpolation.start));
+            }
+            NodeKind::VentoTag(tag) => self.vento_tag(tag.tag),
+            NodeKind::XmlDecl(xml_decl) => {
+                for attr in &xml_decl.attrs {
+                    self.native_attr(attr, "xml");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn element(&mut self, element: &Element<'s>, node: &Node<'s>) {
+        self.slice(TokenKind::TagName, element.tag_name);
+        for attr in &element.attrs {
+            self.attr(attr, element.tag_name);
+        }
+
+        match &*element.children {
+            [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] if self.language != Language::Xml
+                && element.tag_name.eq_ignore_ascii_case("script") =>
+            {
+                match helpers::script_kind(&element.attrs, self.language) {
+                    ScriptKind::Js(lang) => self.code(lang, (text_node.raw, text_node.start)),
+                    ScriptKind::Json => self.code("json", (text_node.raw, text_node.start)),
+                    ScriptKind::Other => self.text(text_node.raw, text_node.start),
+                }
+            }
+            [
+                Node {
+                    kind: NodeKind::Text(text_node),
+                    ..
+                },
+            ] if self.language != Language::Xml
+                && element.tag_name.eq_ignore_ascii_case("style") =>
+            {
+                let lang = helpers::style_lang(&element.attrs);
+                let ext = if lang == "postcss" { "css" } else { lang };
+                self.code(ext, (text_node.raw, text_node.start));
+            }
+            children => self.nodes(children),
+        }
+
+        if !element.self_closing
+            && !element.void_element
+            && let Some(index) = node.raw.rfind("</")
+            && node
+                .raw
+                .get(index + 2..index + 2 + element.tag_name.len())
+                .is_some_and(|name| name.eq_ignore_ascii_case(element.tag_name))
+        {
+            let start = node.start + index + 2;
+            self.push(TokenKind::TagName, start, start + element.tag_name.len());
+        }
+    }
+
+    fn attr(&mut self, attr: &Attribute<'s>, tag_name: &str) {
+        match attr {
+            Attribute::Native(native) => self.native_attr(native, tag_name),
+            Attribute::VueDirective(directive) => self.vue_directive(directive),
+            Attribute::Svelte(SvelteAttribute { name, expr, .. })
+            | Attribute::Astro(AstroAttribute { name, expr, .. }) => {
+                if let Some(name) = name {
+                    self.attr_name(name);
+                }
+                self.code(self.expr_ext, *expr);
+            }
+            Attribute::SvelteAttachment(attachment) => self.code(self.expr_ext, attachment.expr),
+            Attribute::BladeDirective(directive) => self.blade_directive(directive),
+            Attribute::BladeEcho(echo) => self.blade_echo(echo),
+            Attribute::Heex(HeexAttribute { name, expr, .. }) => {
+                if let Some(name) = name {
+                    self.attr_name(name);
+                }
+                self.code("ex", *expr);
+            }
+            Attribute::JinjaBlock(block) => {
+                for child in &block.body {
+                    match child {
+                        JinjaTagOrChildren::Tag(tag) => self.jinja_tag(tag),
+                        JinjaTagOrChildren::Children(attrs) => {
+                            attrs.iter().for_each(|attr| self.attr(attr, tag_name));
+                        }
+                    }
+                }
+            }
+            Attribute::JinjaComment(comment) => self.jinja_comment(comment),
+            Attribute::JinjaTag(tag) => self.jinja_tag(tag),
+            Attribute::JsComment(comment) => {
+                if let Some(start) = self.offset_of(comment.raw) {
+                    let mut end = start + comment.raw.len();
+                    if comment.block && self.source[end..].starts_with("*/") {
+                        end += 2;
+                    }
+                    self.push(TokenKind::Comment, start - 2, end);
+                }
+            }
+            Attribute::VentoTagOrBlock(kind) => self.node_kind(kind),
+        }
+    }
+
+    fn native_attr(&mut self, native: &NativeAttribute<'s>, tag_name: &str) {
+        self.attr_name(native.name);
+        let Some((value, start)) = native
+            .value
+            .filter(|(value, start)| self.offset_of(value) == Some(*start))
+        else {
+            return;
+        };
+        let is_script = tag_name.eq_ignore_ascii_case("script");
+        match self.language {
+            Language::Vue if is_script && native.name == "generic" => {
+                self.code("ts", (value, start));
+            }
+            Language::Svelte if is_script && native.name == "generics" => {
+                self.code("ts", (value, start));
+            }
+            Language::Svelte
+                if value.starts_with('{') && value.ends_with('}') && value.len() > 1 =>
+            {
+                self.code(self.expr_ext, (&value[1..value.len() - 1], start + 1));
+            }
+            Language::Angular
+                if native.name.starts_with(['[', '(']) && native.name.ends_with([']', ')']) =>
+            {
+                self.code(self.expr_ext, (value, start));
+            }
+            Language::Xml => self.push(TokenKind::AttributeValue, start, start + value.len()),
+            _ if native.name.eq_ignore_ascii_case("style") => self.code("css", (value, start)),
+            Language::Angular => self.push(TokenKind::AttributeValue, start, start + value.len()),
+            _ if native.name.starts_with("on") => self.code(self.expr_ext, (value, start)),
+            _ => self.push(TokenKind::AttributeValue, start, start + value.len()),
+        }
+    }
+
+    /// Attribute name which may be a directive of Svelte or Astro, such as `on:click|once`.
+    fn attr_name(&mut self, name: &str) {
+        let Some(start) = self.offset_of(name) else {
+            return;
+        };
+        if let Language::Svelte | Language::Astro = self.language
+            && let Some((directive, rest)) = name.split_once(':')
+            && !directive.is_empty()
+        {
+            self.push(TokenKind::DirectiveName, start, start + directive.len());
+            let mut start = start + directive.len() + 1;
+            let mut parts = rest.split('|');
+            if let Some(arg) = parts.next() {
+                self.push(TokenKind::DirectiveArgument, start, start + arg.len());
+                start += arg.len() + 1;
+            }
+            for modifier in parts {
+                self.push(TokenKind::DirectiveModifier, start, start + modifier.len());
+                start += modifier.len() + 1;
+            }
+        } else {
+            self.push(TokenKind::AttributeName, start, start + name.len());
+        }
+    }
+
+    fn vue_directive(&mut self, directive: &VueDirective<'s>) {
+        let arg_start = directive
+            .arg_and_modifiers
+            .and_then(|arg_and_modifiers| self.offset_of(arg_and_modifiers));
+        if let Some(name_start) = self.offset_of(directive.name) {
+            // `v-` prefix isn't included in directive name
+            let start = name_start.saturating_sub(2);
+            self.push(
+                TokenKind::DirectiveName,
+                start,
+                name_start + directive.name.len(),
+            );
+        } else if let Some(arg_start) = arg_start {
+            // shorthand such as `:` and `@`
+            self.push(TokenKind::DirectiveName, arg_start - 1, arg_start);
+        }
+
+        if let Some((arg_and_modifiers, mut start)) = directive.arg_and_modifiers.zip(arg_start) {
+            let mut rest = arg_and_modifiers;
+            if let Some(s) = rest.strip_prefix(':') {
+                rest = s;
+                start += 1;
+            }
+            let arg_len = if rest.starts_with('[') {
+                rest.find(']').map_or(rest.len(), |index| index + 1)
+            } else {
+                rest.find('.').unwrap_or(rest.len())
+            };
+            self.push(TokenKind::DirectiveArgument, start, start + arg_len);
+            start += arg_len;
+            for modifier in rest[arg_len..].split('.').skip(1) {
+                self.push(
+                    TokenKind::DirectiveModifier,
+                    start + 1,
+                    start + 1 + modifier.len(),
+                );
+                start += modifier.len() + 1;
+            }
+        }
+
+        if let Some((value, start)) = directive.value {
+            match directive.name {
+                "for" => {
+                    if let Some((left, right)) = value
+                        .split_once(" in ")
+                        .or_else(|| value.split_once(" of "))
+                    {
+                        self.code("ts", (left, start));
+                        self.code(self.expr_ext, (right, start + left.len() + 4));
+                    } else {
+                        self.code(self.expr_ext, (value, start));
+                    }
+                }
+                "#" | "slot" => self.code("ts", (value, start)),
+                _ => self.code(self.expr_ext, (value, start)),
+            }
+        }
+    }
+
+    fn jinja_tag(&mut self, tag: &JinjaTag<'s>) {
+        if self.language == Language::GoTemplate
+            && helpers::go_template_comment(tag.content).is_some()
+        {
+            let (open, close) = self.dialect.tag();
+            let mut end = tag.start + tag.content.len();
+            if self.source[end..].starts_with(close) {
+                end += close.len();
+            }
+            self.push(
+                TokenKind::Comment,
+                tag.start.saturating_sub(open.len()),
+                end,
+            );
+            return;
+        }
+        self.known_delimiters(self.dialect.tag(), tag.start, tag.start + tag.content.len());
+        let (expr_ext, stmt_ext) = self.dialect.external_formatter_exts();
+        let is_expr = dialect::output_marker(self.dialect, tag.content).is_some()
+            && !self
+                .dialect
+                .is_block_start(self.dialect.tag_name(tag.content), tag.content);
+        self.code(
+            if is_expr { expr_ext } else { stmt_ext },
+            (tag.content, tag.start),
+        );
+    }
+
+    fn jinja_comment(&mut self, comment: &JinjaComment<'s>) {
+        let Some(start) = self.offset_of(comment.raw) else {
+            return;
+        };
+        let (open, close) = self.dialect.comment();
+        let mut end = start + comment.raw.len();
+        if self.source[end..].starts_with(close) {
+            end += close.len();
+        }
+        self.push(TokenKind::Comment, start.saturating_sub(open.len()), end);
+    }
+
+    fn blade_directive(&mut self, directive: &BladeDirective<'s>) {
+        if let Some(start) = self.offset_of(directive.name) {
+            self.push(
+                TokenKind::DirectiveName,
+                start.saturating_sub(1),
+                start + directive.name.len(),
+            );
+        }
+        if let Some(args) = directive.args {
+            self.code("php", args);
+        }
+    }
+
+    fn blade_echo(&mut self, echo: &BladeEcho<'s>) {
+        let delimiters = if echo.escaped {
+            ("{{", "}}")
+        } else {
+            ("{!!", "!!}")
+        };
+        self.known_delimiters(delimiters, echo.start, echo.start + echo.expr.len());
+        self.code("php", (echo.expr, echo.start));
+    }
+
+    fn vento_tag(&mut self, tag: &str) {
+        if let Some(start) = self.offset_of(tag) {
+            self.known_delimiters(("{{", "}}"), start, start + tag.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(code: &str, language: Language) -> Vec<(TokenKind<'_>, &str)> {
+        tokenize(code, language, &Default::default())
+            .unwrap()
+            .into_iter()
+            .map(|token| (token.kind, &code[token.start..token.end]))
+            .collect()
+    }
+
+    #[test]
+    fn svelte() {
+        assert_eq!(
+            tokens(
+                "<button on:click|once={go}>{#if ok}<!-- c -->{x}{/if}</button>",
+                Language::Svelte
+            ),
+            [
+                (TokenKind::TagName, "button"),
+                (TokenKind::DirectiveName, "on"),
+                (TokenKind::DirectiveArgument, "click"),
+                (TokenKind::DirectiveModifier, "once"),
+                (TokenKind::Code { ext: "js" }, "go"),
+                (TokenKind::Code { ext: "js" }, "ok"),
+                (TokenKind::Comment, "<!-- c -->"),
+                (TokenKind::Delimiter, "{"),
+                (TokenKind::Code { ext: "js" }, "x"),
+                (TokenKind::Delimiter, "}"),
+                (TokenKind::TagName, "button"),
+            ]
+        );
+    }
+
+    #[test]
+    fn jinja() {
+        assert_eq!(
+            tokens(
+                "{# c #}<ul>{% for i in items %}<li class=a>{{- i }}</li>{% endfor %}</ul>",
+                Language::Jinja
+            ),
+            [
+                (TokenKind::Comment, "{# c #}"),
+                (TokenKind::TagName, "ul"),
+                (TokenKind::Delimiter, "{%"),
+                (
+                    TokenKind::Code {
+                        ext: "markup-fmt-jinja-stmt"
+                    },
+                    " for i in items "
+                ),
+                (TokenKind::Delimiter, "%}"),
+                (TokenKind::TagName, "li"),
+                (TokenKind::AttributeName, "class"),
+                (TokenKind::AttributeValue, "a"),
+                (TokenKind::Delimiter, "{{"),
+                (
+                    TokenKind::Code {
+                        ext: "markup-fmt-jinja-expr"
+                    },
+                    " i "
+                ),
+                (TokenKind::Delimiter, "}}"),
+                (TokenKind::TagName, "li"),
+                (TokenKind::Delimiter, "{%"),
+                (
+                    TokenKind::Code {
+                        ext: "markup-fmt-jinja-stmt"
+                    },
+                    " endfor "
+                ),
+                (TokenKind::Delimiter, "%}"),
+                (TokenKind::TagName, "ul"),
+            ]
+        );
+    }
+
+    #[test]
+    fn go_template_comments() {
+        assert_eq!(
+            tokens("{{/* a */}}<p>{{- /* b }} */ -}}</p>", Language::GoTemplate),
+            [
+                (TokenKind::Comment, "{{/* a */}}"),
+                (TokenKind::TagName, "p"),
+                (TokenKind::Comment, "{{- /* b }} */ -}}"),
+                (TokenKind::TagName, "p"),
+            ]
+        );
+    }
+
+    #[test]
+    fn vue_script_and_directive_modifiers() {
+        assert_eq!(
+            tokens(
+                "<script setup lang=\"ts\">let a</script><a @click.stop.prevent=\"f\" v-for=\"x in xs\">t</a>",
+                Language::Vue
+            ),
+            [
+                (TokenKind::TagName, "script"),
+                (TokenKind::AttributeName, "setup"),
+                (TokenKind::AttributeName, "lang"),
+                (TokenKind::AttributeValue, "ts"),
+                (TokenKind::Code { ext: "ts" }, "let a"),
+                (TokenKind::TagName, "script"),
+                (TokenKind::TagName, "a"),
+                (TokenKind::DirectiveName, "@"),
+                (TokenKind::DirectiveArgument, "click"),
+                (TokenKind::DirectiveModifier, "stop"),
+                (TokenKind::DirectiveModifier, "prevent"),
+                (TokenKind::Code { ext: "ts" }, "f"),
+                (TokenKind::DirectiveName, "v-for"),
+                (TokenKind::Code { ext: "ts" }, "x"),
+                (TokenKind::Code { ext: "ts" }, "xs"),
+                (TokenKind::Text, "t"),
+                (TokenKind::TagName, "a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn svelte_expr_ext() {
+        let code = "<script lang=\"ts\"></script>{x}";
+        let ext = |options: &LanguageOptions| {
+            tokenize(code, Language::Svelte, options)
+                .unwrap()
+                .into_iter()
+                .find(|token| &code[token.start..token.end] == "x")
+                .map(|token| token.kind)
+        };
+        assert_eq!(
+            ext(&Default::default()),
+            Some(TokenKind::Code { ext: "ts" })
+        );
+
+        let options = LanguageOptions {
+            svelte_expr_lang: Some(crate::config::ExprLang::Tsx),
+            ..Default::default()
+        };
+        assert_eq!(ext(&options), Some(TokenKind::Code { ext: "tsx" }));
+    }
+
+    #[test]
+    fn skip_transformed_nodes() {
+        use crate::transform::{self, Strings, Transformer};
+
+        let code = "<ul><li>a</li></ul><p onclick=\"go()\">b</p>";
+        let mut root = Parser::new(code, Language::Html).parse_root().unwrap();
+        let strings = Strings::default();
+        let transformer = Transformer::new(&strings, Language::Html);
+        transform::visit_elements_mut(&mut root.children, &mut |element| {
+            if element.tag_name == "ul" {
+                element.children = transformer.parse_nodes("<li>new item</li>").unwrap();
+            } else if let Some(Attribute::Native(attr)) = element.attrs.first_mut() {
+                transformer.set_attr_value(attr, "stop()");
+            }
+        });
+        let tokens = collect(&root, code, Language::Html, &Default::default())
+            .into_iter()
+            .map(|token| (token.kind, &code[token.start..token.end]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::TagName, "ul"),
+                (TokenKind::TagName, "ul"),
+                (TokenKind::TagName, "p"),
+                (TokenKind::AttributeName, "onclick"),
+                (TokenKind::Text, "b"),
+                (TokenKind::TagName, "p"),
+            ]
+        );
+    }
+}
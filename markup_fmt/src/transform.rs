@@ -0,0 +1,192 @@
+//! Hooks for transforming AST before printing.
+//!
+//! See [`format_text_with_transform`](crate::format_text_with_transform).
+
+use crate::{
+    Language, SyntaxError,
+    ast::{
+        AstroExprChild, Attribute, Element, JinjaTagOrChildren, NativeAttribute, Node, NodeKind,
+        VentoTagOrChildren,
+    },
+    parser::Parser,
+};
+use std::cell::RefCell;
+
+#[derive(Default)]
+/// Storage of owned strings created during transformation.
+/// Strings are kept alive until formatting is finished.
+pub(crate) struct Strings(RefCell<Vec<Box<str>>>);
+
+impl Strings {
+    fn alloc(&self, s: String) -> &str {
+        let s = s.into_boxed_str();
+        let ptr: *const str = &*s;
+        self.0.borrow_mut().push(s);
+        // Boxed strings are never dropped or mutated until `Strings` itself is dropped,
+        // and moving the box doesn't move its heap content.
+        unsafe { &*ptr }
+    }
+}
+
+/// Helpers for building and replacing AST parts with owned strings.
+pub struct Transformer<'s> {
+    strings: &'s Strings,
+    language: Language,
+}
+
+impl<'s> Transformer<'s> {
+    pub(crate) fn new(strings: &'s Strings, language: Language) -> Self {
+        Self { strings, language }
+    }
+
+    /// Store owned string, so it can be used in AST.
+    pub fn alloc_str(&self, s: impl Into<String>) -> &'s str {
+        self.strings.alloc(s.into())
+    }
+
+    /// Rename tag name of element, for both opening tag and closing tag.
+    pub fn rename_element(&self, element: &mut Element<'s>, tag_name: impl Into<String>) {
+        element.tag_name = self.alloc_str(tag_name);
+    }
+
+    /// Create a native attribute. Pass `None` as value for attribute without value.
+    pub fn native_attr(
+        &self,
+        name: impl Into<String>,
+        value: Option<impl Into<String>>,
+    ) -> Attribute<'s> {
+        Attribute::Native(NativeAttribute {
+            name: self.alloc_str(name),
+            value: value.map(|value| (self.alloc_str(value), 0)),
+            quote: None,
+            start: 0,
+        })
+    }
+
+    /// Replace value of native attribute.
+    pub fn set_attr_value(&self, attr: &mut NativeAttribute<'s>, value: impl Into<String>) {
+        let start = attr.value.map(|(_, start)| start).unwrap_or_default();
+        attr.value = Some((self.alloc_str(value), start));
+        attr.quote = None;
+    }
+
+    /// Parse code snippet into nodes with the language being formatted.
+    /// Returned nodes can be used to replace existing nodes.
+    ///
+    /// Byte offsets in returned nodes are relative to the snippet.
+    pub fn parse_nodes(&self, code: impl Into<String>) -> Result<Vec<Node<'s>>, SyntaxError> {
+        let code = self.alloc_str(code);
+        Parser::new(code, self.language)
+            .parse_root()
+            .map(|root| root.children)
+    }
+}
+
+/// Visit every list of nodes recursively, including the given one.
+///
+/// The callback can insert, remove or replace nodes in the list.
+/// Children of nodes in that list will be visited after the callback returns.
+pub fn visit_children_mut<'s, F>(children: &mut Vec<Node<'s>>, f: &mut F)
+where
+    F: FnMut(&mut Vec<Node<'s>>),
+{
+    f(children);
+    children.iter_mut().for_each(|node| match &mut node.kind {
+        NodeKind::AngularFor(angular_for) => {
+            visit_children_mut(&mut angular_for.children, f);
+            if let Some(empty) = &mut angular_for.empty {
+                visit_children_mut(empty, f);
+            }
+        }
+        NodeKind::AngularGenericBlocks(blocks) => blocks
+            .iter_mut()
+            .for_each(|block| visit_children_mut(&mut block.children, f)),
+        NodeKind::AngularIf(angular_if) => {
+            visit_children_mut(&mut angular_if.children, f);
+            angular_if
+                .else_if_blocks
+                .iter_mut()
+                .for_each(|block| visit_children_mut(&mut block.children, f));
+            if let Some(children) = &mut angular_if.else_children {
+                visit_children_mut(children, f);
+            }
+        }
+        NodeKind::AngularSwitch(angular_switch) => angular_switch.arms.iter_mut().for_each(|arm| {
+            if let Some(children) = &mut arm.children {
+                visit_children_mut(children, f);
+            }
+        }),
+        NodeKind::AstroExpr(astro_expr) => astro_expr.children.iter_mut().for_each(|child| {
+            if let AstroExprChild::Template(nodes) = child {
+                visit_children_mut(nodes, f);
+            }
+        }),
+        NodeKind::BladeBlock(blade_block) => blade_block
+            .children
+            .iter_mut()
+            .for_each(|nodes| visit_children_mut(nodes, f)),
+        NodeKind::Element(element) => visit_children_mut(&mut element.children, f),
+        NodeKind::JinjaBlock(jinja_block) => jinja_block.body.iter_mut().for_each(|child| {
+            if let JinjaTagOrChildren::Children(nodes) = child {
+                visit_children_mut(nodes, f);
+            }
+        }),
+        NodeKind::MustacheBlock(mustache_block) => mustache_block
+            .children
+            .iter_mut()
+            .for_each(|nodes| visit_children_mut(nodes, f)),
+        NodeKind::RazorBlock(razor_block) => razor_block
+            .branches
+            .iter_mut()
+            .for_each(|branch| visit_children_mut(&mut branch.children, f)),
+        NodeKind::SvelteAwaitBlock(svelte_await) => {
+            visit_children_mut(&mut svelte_await.children, f);
+            if let Some(then_block) = &mut svelte_await.then_block {
+                visit_children_mut(&mut then_block.children, f);
+            }
+            if let Some(catch_block) = &mut svelte_await.catch_block {
+                visit_children_mut(&mut catch_block.children, f);
+            }
+        }
+        NodeKind::SvelteEachBlock(svelte_each) => {
+            visit_children_mut(&mut svelte_each.children, f);
+            if let Some(children) = &mut svelte_each.else_children {
+                visit_children_mut(children, f);
+            }
+        }
+        NodeKind::SvelteIfBlock(svelte_if) => {
+            visit_children_mut(&mut svelte_if.children, f);
+            svelte_if
+                .else_if_blocks
+                .iter_mut()
+                .for_each(|block| visit_children_mut(&mut block.children, f));
+            if let Some(children) = &mut svelte_if.else_children {
+                visit_children_mut(children, f);
+            }
+        }
+        NodeKind::SvelteKeyBlock(svelte_key) => visit_children_mut(&mut svelte_key.children, f),
+        NodeKind::SvelteSnippetBlock(svelte_snippet) => {
+            visit_children_mut(&mut svelte_snippet.children, f)
+        }
+        NodeKind::VentoBlock(vento_block) => vento_block.body.iter_mut().for_each(|child| {
+            if let VentoTagOrChildren::Children(nodes) = child {
+                visit_children_mut(nodes, f);
+            }
+        }),
+        _ => {}
+    });
+}
+
+/// Visit every element recursively.
+pub fn visit_elements_mut<'s, F>(children: &mut Vec<Node<'s>>, f: &mut F)
+where
+    F: FnMut(&mut Element<'s>),
+{
+    visit_children_mut(children, &mut |nodes: &mut Vec<Node<'s>>| {
+        nodes.iter_mut().for_each(|node| {
+            if let NodeKind::Element(element) = &mut node.kind {
+                f(element);
+            }
+        })
+    });
+}
@@ -1,13 +1,21 @@
 use insta::{Settings, assert_snapshot, glob};
-use markup_fmt::{Language, config::FormatOptions, detect_language, format_text};
+use markup_fmt::{
+    Language, config::FormatOptions, detect_language, format_glimmer_script, format_markdown_html,
+    format_text,
+};
 use std::{collections::HashMap, fs, path::Path};
 
 #[test]
 fn fmt_snapshot() {
-    let pattern = "fmt/**/*.{html,vue,svelte,astro,jinja,njk,vto,mustache,hbs,xml}";
+    let pattern = "fmt/**/*.{html,vue,svelte,astro,jinja,njk,vto,mustache,hbs,xml,liquid,django,djhtml,gohtml,tmpl,erb,ejs,php,cshtml,razor,heex,gjs,gts,mdx,md}";
     glob!(pattern, |path| {
         let input = fs::read_to_string(path).unwrap();
-        let language = detect_language(path).unwrap();
+        let language = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gjs" | "gts") => Language::Glimmer,
+            // Markdown files are hosts of HTML blocks
+            Some("md") => Language::Html,
+            _ => detect_language(path).unwrap(),
+        };
 
         let options = fs::read_to_string(path.with_file_name("config.toml"))
             .map(|config_file| {
@@ -39,10 +47,19 @@ fn run_format_test(
     options: &FormatOptions,
     language: Language,
 ) -> String {
-    let output = format_text(input, language, options, |code, _| Ok(code.into()))
+    let format = |input: &str| {
+        if language == Language::Glimmer {
+            format_glimmer_script(input, options, |code, _| Ok(code.into()))
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            format_markdown_html(input, options, |code, _| Ok(code.into()))
+        } else {
+            format_text(input, language, options, |code, _| Ok(code.into()))
+        }
+    };
+    let output = format(input)
         .map_err(|err| format!("failed to format '{}': {:?}", path.display(), err))
         .unwrap();
-    let regression_format = format_text(&output, language, options, |code, _| Ok(code.into()))
+    let regression_format = format(&output)
         .map_err(|err| {
             format!(
                 "syntax error in stability test '{}': {err:?}",
@@ -0,0 +1,8 @@
+<x-alert type="error" :message="$message" class="mb-4" {{ $attributes }} />
+<div @class(['p-4', 'font-bold' => $active]) @click="open = true">
+<input type="checkbox" name="active" value="active" @checked(old('active', $user->active)) />
+<button type="submit" @disabled($errors->isNotEmpty())>Submit</button>
+</div>
+<span class="{{ $cls }}">text</span>
+<x-card :title="  $post->title  " :items="[ 'a', 'b' ]" ::class="{ active: open }" :class="$cls" />
+<div :title="  $notComponent  "></div>
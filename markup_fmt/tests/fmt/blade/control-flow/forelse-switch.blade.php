@@ -0,0 +1,26 @@
+<ul>
+@forelse ($posts as $post)
+<li>{{$post->title}}</li>
+@empty
+<li>No posts</li>
+@endforelse
+</ul>
+@switch($i)
+@case(1)
+First case...
+@break
+@case(2)
+Second case...
+@break
+@default
+Default case...
+@endswitch
+@unless(Auth::check())
+<a href="/login">Log in</a>
+@endunless
+@auth
+<span>Welcome back</span>
+@endauth
+@isset($records)
+<table></table>
+@endisset
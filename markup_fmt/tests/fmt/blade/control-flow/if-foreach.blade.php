@@ -0,0 +1,19 @@
+@extends('layouts.app')
+
+@section('title', 'Users')
+
+@section('content')
+<div>
+@if(count($users) > 0)
+<ul>
+@foreach($users as $user)
+<li>{{ $user->name }}</li>
+@endforeach
+</ul>
+@elseif($loading)
+<p>Loading...</p>
+@else
+<p>No users.</p>
+@endif
+</div>
+@endsection
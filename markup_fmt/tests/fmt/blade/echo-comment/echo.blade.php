@@ -0,0 +1,11 @@
+<p>Hello, {{$name}}. You have {{ count($messages) }} messages.</p>
+<div>{!!$html!!}</div>
+<p>@{{ not.processed }} and user@example.com</p>
+{{-- This comment will not be present in the rendered HTML --}}
+{{--
+   multi
+   line
+--}}
+<script>
+var app = {{ Js::from($array) }};
+</script>
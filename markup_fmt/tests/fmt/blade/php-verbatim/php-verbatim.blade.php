@@ -0,0 +1,14 @@
+<div>
+@php
+$counter = 1;
+$items = [];
+@endphp
+@verbatim
+<div class="container">
+    Hello, {{ name }}.
+</div>
+@endverbatim
+</div>
+@php($total = 0)
+@csrf
+@method('PUT')
@@ -0,0 +1,2 @@
+<p>email me @home(ish) or at user@example.com, follow @username</p>
+<p>@if($a) yes @endif</p>
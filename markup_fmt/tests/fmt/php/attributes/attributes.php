@@ -0,0 +1,4 @@
+<div class="post <?= $active ? 'active' : '' ?>" id="post-<?php the_ID(); ?>">
+<input type="checkbox" <?php if ($checked): ?>checked<?php endif; ?>>
+<a href="<?= esc_url(get_permalink()) ?>" title='<?= $title ?>'>link</a>
+</div>
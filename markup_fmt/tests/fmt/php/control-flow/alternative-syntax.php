@@ -0,0 +1,19 @@
+<ul>
+<?php foreach ($items as $item): ?>
+<li><?= $item->name ?></li>
+<?php endforeach; ?>
+</ul>
+<?php if ($user): ?>
+<p>Hello, <?= htmlspecialchars($user->name) ?>!</p>
+<?php elseif ($guest): ?>
+<p>Hello, guest!</p>
+<?php else: ?>
+<a href="/login">Log in</a>
+<?php endif; ?>
+<?php switch ($type): ?>
+<?php case 'a': ?>
+<span>A</span>
+<?php break; ?>
+<?php default: ?>
+<span>other</span>
+<?php endswitch; ?>
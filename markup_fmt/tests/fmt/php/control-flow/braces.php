@@ -0,0 +1,7 @@
+<?php if (have_posts()) { ?>
+<?php while (have_posts()) { the_post(); ?>
+<article><?php the_content(); ?></article>
+<?php } ?>
+<?php } else { ?>
+<p>No posts.</p>
+<?php } ?>
@@ -0,0 +1,7 @@
+<?php if ($x): ?><div class="wrap"><?php endif; ?>
+<p>content</p>
+<?php if ($x): ?></div><?php endif; ?>
+<?php if ($a) { echo 1; } ?>
+<?php while (have_posts()) : the_post(); ?>
+<h2><?php the_title(); ?></h2>
+<?php endwhile; ?>
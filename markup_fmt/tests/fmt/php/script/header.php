@@ -0,0 +1,9 @@
+<?php
+$title = get_the_title();
+$items = get_items();
+?>
+<!doctype html>
+<html><head><title><?= $title ?></title></head>
+<body><?php get_header(); ?></body></html>
+<?php
+get_footer();